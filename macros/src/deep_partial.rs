@@ -0,0 +1,140 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Field, Fields};
+
+/// Options from the `#[deep_partial(...)]` attributes of a field
+#[derive(Default)]
+struct FieldAttrs {
+    replace: bool,
+}
+
+impl FieldAttrs {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("deep_partial")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("replace") {
+                    attrs.replace = true;
+                } else {
+                    return Err(meta.error("unknown deep_partial attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+pub fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let partial_name = format_ident!("{}Partial", name);
+    let doc = format!("Partial of [`{}`] generated by `#[derive(DeepPartial)]`", name);
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input.generics, "DeepPartial does not support generic structs"));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(input, "DeepPartial only supports structs"));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(input, "DeepPartial only supports structs with named fields"));
+    };
+
+    let deep_partial = quote! { ::dioxus_ui::types::deep_partial::DeepPartial };
+    let mut partial_fields = Vec::new();
+    let mut builders = Vec::new();
+    let mut applies = Vec::new();
+    let mut merges = Vec::new();
+    let mut into_partials = Vec::new();
+    for field in fields.named.iter() {
+        let attrs = FieldAttrs::parse(field)?;
+        let ident = field.ident.as_ref().expect("named field");
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+        let builder = format_ident!("with_{}", ident);
+
+        // Replaced fields don't need to implement DeepPartial themselves
+        let (partial_type, apply, merge, into_partial) = if attrs.replace {
+            (
+                quote! { #ty },
+                quote! { self.#ident = partial },
+                quote! { b },
+                quote! { self.#ident },
+            )
+        } else {
+            (
+                quote! { <#ty as #deep_partial>::Partial },
+                quote! { #deep_partial::apply(&mut self.#ident, partial) },
+                quote! { <#ty as #deep_partial>::merge(a, b) },
+                quote! { #deep_partial::into_partial(self.#ident) },
+            )
+        };
+
+        partial_fields.push(quote! {
+            #field_vis #ident: ::std::option::Option<#partial_type>
+        });
+        builders.push(quote! {
+            pub fn #builder(mut self, #ident: #partial_type) -> Self {
+                self.#ident = ::std::option::Option::Some(#ident);
+                self
+            }
+        });
+        applies.push(quote! {
+            if let ::std::option::Option::Some(partial) = partial.#ident {
+                #apply;
+            }
+        });
+        merges.push(quote! {
+            #ident: match (a.#ident, b.#ident) {
+                (::std::option::Option::Some(a), ::std::option::Option::Some(b)) => ::std::option::Option::Some(#merge),
+                (a, b) => b.or(a),
+            }
+        });
+        into_partials.push(quote! {
+            #ident: ::std::option::Option::Some(#into_partial)
+        });
+    }
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Default, PartialEq)]
+        #vis struct #partial_name {
+            #(#partial_fields,)*
+        }
+
+        impl #partial_name {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            #(#builders)*
+        }
+
+        impl #deep_partial for #name {
+            type Partial = #partial_name;
+
+            fn apply(&mut self, partial: Self::Partial) {
+                #(#applies)*
+            }
+
+            fn merge(a: Self::Partial, b: Self::Partial) -> Self::Partial {
+                #partial_name {
+                    #(#merges,)*
+                }
+            }
+
+            fn into_partial(self) -> Self::Partial {
+                #partial_name {
+                    #(#into_partials,)*
+                }
+            }
+        }
+
+        impl ::std::convert::From<#name> for #partial_name {
+            fn from(value: #name) -> Self {
+                #deep_partial::into_partial(value)
+            }
+        }
+    })
+}
@@ -0,0 +1,59 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Field, Fields};
+
+fn is_skipped(field: &Field) -> syn::Result<bool> {
+    let mut skip = false;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("lens")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown lens attribute"))
+            }
+        })?;
+    }
+    Ok(skip)
+}
+
+pub fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let lens_name = format_ident!("{}Lens", name);
+    let doc = format!("Lenses to the fields of [`{}`] generated by `#[derive(Lens)]`", name);
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input.generics, "Lens does not support generic structs"));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(input, "Lens only supports structs"));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(input, "Lens only supports structs with named fields"));
+    };
+
+    let lens = quote! { ::dioxus_ui::types::lens::Lens };
+    let mut lenses = Vec::new();
+    for field in fields.named.iter() {
+        if is_skipped(field)? {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named field");
+        let (field_vis, ty) = (&field.vis, &field.ty);
+        lenses.push(quote! {
+            #field_vis fn #ident() -> #lens<#name, #ty> {
+                #lens::new(|source| &source.#ident, |source| &mut source.#ident)
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[doc = #doc]
+        #vis struct #lens_name;
+
+        impl #lens_name {
+            #(#lenses)*
+        }
+    })
+}
@@ -0,0 +1,102 @@
+//! Derive macros of `dioxus_ui`
+//!
+//! The generated code refers to the library as `::dioxus_ui`.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod deep_partial;
+mod lens;
+mod nested;
+mod table_row;
+
+/// Generate `TableRow::render_map` from the named fields of a struct
+///
+/// Each field becomes a column keyed by its name, shown by `Display` and sorted by `PartialOrd`.
+/// Numeric fields are right-aligned and totaled by their value.
+///
+/// Field attributes:
+/// - `#[table(label = "...")]` header label (default is the field name)
+/// - `#[table(row_header)]` render as a row header
+/// - `#[table(hidden)]` start hidden
+/// - `#[table(width = "...")]` initial column width
+/// - `#[table(align = "...")]` text alignment
+/// - `#[table(total)]` show the total in the footer
+/// - `#[table(skip)]` no column for the field
+#[proc_macro_derive(TableRow, attributes(table))]
+pub fn derive_table_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    table_row::derive(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Generate `DeepPartial` and a `FooPartial` struct from the named fields of a struct
+///
+/// Every field of `FooPartial` is an `Option` of the field's partial, so nested structs
+/// deriving `DeepPartial` are partialized recursively. `FooPartial` gets `new` and `with_*` builders.
+///
+/// Field attributes:
+/// - `#[deep_partial(replace)]` replace the field as a whole (for types without `DeepPartial`)
+#[proc_macro_derive(DeepPartial, attributes(deep_partial))]
+pub fn derive_deep_partial(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    deep_partial::derive(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Implement `NestedKeyOf` for a struct with named fields or an enum
+///
+/// Struct fields are keyed by name. Enum values are keyed by the variant name,
+/// followed by the field name (or index for tuple variants with several fields).
+///
+/// Field and variant attributes:
+/// - `#[nested(rename = "...")]` key in paths (default is the field or variant name)
+/// - `#[nested(skip)]` leave the field out of paths (fields only)
+#[proc_macro_derive(NestedKeyOf, attributes(nested))]
+pub fn derive_nested_key_of(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    nested::derive_nested_key_of(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Implement `NestedValueOf` and `From<T> for NestedValue`
+///
+/// Structs become objects. Unit variants become a string of the name,
+/// and other variants an object with the name as the only key.
+/// Accepts the same `#[nested(...)]` attributes as `NestedKeyOf`.
+#[proc_macro_derive(NestedValueOf, attributes(nested))]
+pub fn derive_nested_value_of(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    nested::derive_nested_value_of(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Implement `NestedValueSetter`, `FromNestedValue` and `TryFrom<NestedValue>`
+///
+/// Reads the shape written by `NestedValueOf`. Skipped fields are filled with `Default`.
+/// Accepts the same `#[nested(...)]` attributes as `NestedKeyOf`.
+#[proc_macro_derive(NestedValueSetter, attributes(nested))]
+pub fn derive_nested_value_setter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    nested::derive_nested_value_setter(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Generate a `FooLens` with a `Lens` to each named field of a struct
+///
+/// `FooLens::name()` returns a `Lens<Foo, T>` for the field `name: T`.
+///
+/// Field attributes:
+/// - `#[lens(skip)]` no lens for the field
+#[proc_macro_derive(Lens, attributes(lens))]
+pub fn derive_lens(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    lens::derive(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
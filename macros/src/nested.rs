@@ -0,0 +1,454 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::{Attribute, Data, DeriveInput, Fields, Ident, LitStr};
+
+/// Options from the `#[nested(...)]` attributes of a field or variant
+#[derive(Default)]
+struct NestedAttrs {
+    rename: Option<String>,
+    skip: bool,
+}
+
+impl NestedAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut nested_attrs = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("nested")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    nested_attrs.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("skip") {
+                    nested_attrs.skip = true;
+                } else {
+                    return Err(meta.error("unknown nested attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(nested_attrs)
+    }
+}
+
+/// Field of a struct or variant
+struct NestedField {
+    /// `None` for tuple fields
+    ident: Option<Ident>,
+    index: usize,
+    /// Key of the field in paths
+    key: String,
+    /// Name bound to the field in patterns
+    binding: Ident,
+    skip: bool,
+}
+
+enum Shape {
+    Named(Vec<NestedField>),
+    Unnamed(Vec<NestedField>),
+    Unit,
+}
+
+struct NestedVariant {
+    ident: Ident,
+    key: String,
+    shape: Shape,
+}
+
+enum Input {
+    /// Always `Shape::Named`
+    Struct(Shape),
+    Enum(Vec<NestedVariant>),
+}
+
+fn parse_shape(fields: &Fields) -> syn::Result<Shape> {
+    let parse_fields = |fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>| {
+        fields.iter().enumerate().map(|(index, field)| {
+            let attrs = NestedAttrs::parse(&field.attrs)?;
+            let name = field.ident.as_ref().map_or_else(|| index.to_string(), |ident| ident.unraw().to_string());
+            Ok(NestedField {
+                ident: field.ident.clone(),
+                index,
+                key: attrs.rename.unwrap_or_else(|| name.clone()),
+                binding: format_ident!("field_{}", name),
+                skip: attrs.skip,
+            })
+        }).collect::<syn::Result<Vec<_>>>()
+    };
+    Ok(match fields {
+        Fields::Named(fields) => Shape::Named(parse_fields(&fields.named)?),
+        Fields::Unnamed(fields) => Shape::Unnamed(parse_fields(&fields.unnamed)?),
+        Fields::Unit => Shape::Unit,
+    })
+}
+
+fn parse_input(input: &DeriveInput, derive_name: &str) -> syn::Result<Input> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input.generics, format!("{} does not support generic types", derive_name)));
+    }
+    match &input.data {
+        Data::Struct(data) => match parse_shape(&data.fields)? {
+            shape @ Shape::Named(_) => Ok(Input::Struct(shape)),
+            _ => Err(syn::Error::new_spanned(input, format!("{} only supports structs with named fields", derive_name))),
+        },
+        Data::Enum(data) => {
+            let mut variants = Vec::new();
+            for variant in data.variants.iter() {
+                let attrs = NestedAttrs::parse(&variant.attrs)?;
+                if attrs.skip {
+                    return Err(syn::Error::new_spanned(variant, "variants can't be skipped"));
+                }
+                variants.push(NestedVariant {
+                    ident: variant.ident.clone(),
+                    key: attrs.rename.unwrap_or_else(|| variant.ident.unraw().to_string()),
+                    shape: parse_shape(&variant.fields)?,
+                });
+            }
+            Ok(Input::Enum(variants))
+        }
+        Data::Union(_) => Err(syn::Error::new_spanned(input, format!("{} does not support unions", derive_name))),
+    }
+}
+
+fn bound(fields: &[NestedField]) -> impl Iterator<Item = &NestedField> {
+    fields.iter().filter(|field| !field.skip)
+}
+
+/// Pattern binding the fields that are not skipped
+fn pattern(path: &TokenStream, shape: &Shape) -> TokenStream {
+    match shape {
+        Shape::Named(fields) => {
+            let bindings = bound(fields).map(|field| {
+                let (ident, binding) = (&field.ident, &field.binding);
+                quote! { #ident: #binding }
+            });
+            quote! { #path { #(#bindings,)* .. } }
+        }
+        Shape::Unnamed(fields) => {
+            let bindings = fields.iter().map(|field| match field.skip {
+                true => quote! { _ },
+                false => field.binding.to_token_stream(),
+            });
+            quote! { #path(#(#bindings),*) }
+        }
+        Shape::Unit => quote! { #path },
+    }
+}
+
+/// Single tuple field, addressed without an index
+fn newtype(shape: &Shape) -> Option<&NestedField> {
+    match shape {
+        Shape::Unnamed(fields) if fields.len() == 1 && !fields[0].skip => fields.first(),
+        _ => None,
+    }
+}
+
+fn fields_of(shape: &Shape) -> &[NestedField] {
+    match shape {
+        Shape::Named(fields) | Shape::Unnamed(fields) => fields,
+        Shape::Unit => &[],
+    }
+}
+
+pub fn derive_nested_key_of(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let key_of = quote! { ::dioxus_ui::types::nested_key_of };
+
+    let nested_key = |field: &NestedField| match field.ident {
+        Some(_) => {
+            let key = &field.key;
+            quote! { #key_of::NestedKey::String(#key.to_string()) }
+        }
+        None => {
+            let index = field.index;
+            quote! { #key_of::NestedKey::Number(#index) }
+        }
+    };
+    let keys_of_fields = |fields: &[NestedField]| {
+        let chains = bound(fields).map(|field| {
+            let (key, binding) = (nested_key(field), &field.binding);
+            quote! { .chain(#key_of::prefix_nested_keys(#key, #key_of::NestedKeyOf::get_nested_keys(#binding))) }
+        });
+        quote! { ::std::vec::Vec::<#key_of::NestedKeyPath>::new().into_iter()#(#chains)*.collect() }
+    };
+
+    let body = match parse_input(input, "NestedKeyOf")? {
+        Input::Struct(shape) => {
+            let pattern = pattern(&quote! { Self }, &shape);
+            let keys = keys_of_fields(fields_of(&shape));
+            quote! {
+                let #pattern = self;
+                #keys
+            }
+        }
+        Input::Enum(variants) => {
+            let arms = variants.iter().map(|variant| {
+                let (ident, key) = (&variant.ident, &variant.key);
+                let pattern = pattern(&quote! { Self::#ident }, &variant.shape);
+                let keys = match (&variant.shape, newtype(&variant.shape)) {
+                    (Shape::Unit, _) => quote! { ::std::vec::Vec::new() },
+                    (_, Some(field)) => {
+                        let binding = &field.binding;
+                        quote! { #key_of::prefix_nested_keys(#key_of::NestedKey::String(#key.to_string()), #key_of::NestedKeyOf::get_nested_keys(#binding)) }
+                    }
+                    (shape, None) => {
+                        let keys = keys_of_fields(fields_of(shape));
+                        quote! { #key_of::prefix_nested_keys(#key_of::NestedKey::String(#key.to_string()), #keys) }
+                    }
+                };
+                quote! { #pattern => #keys, }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #key_of::NestedKeyOf for #name {
+            fn get_nested_keys(&self) -> ::std::vec::Vec<#key_of::NestedKeyPath> {
+                #body
+            }
+        }
+    })
+}
+
+pub fn derive_nested_value_of(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let value_of = quote! { ::dioxus_ui::types::nested_value_of };
+    let into_value = |binding: &Ident| quote! { ::std::convert::Into::<#value_of::NestedValue>::into(#binding) };
+
+    // Value of the bound fields (skipped tuple fields are `Null` to keep the indices)
+    let payload = |shape: &Shape| match shape {
+        Shape::Named(fields) => {
+            let entries = bound(fields).map(|field| {
+                let (key, value) = (&field.key, into_value(&field.binding));
+                quote! { (#key.to_string(), #value) }
+            });
+            quote! { #value_of::NestedValue::Object(::std::collections::HashMap::from([#(#entries),*])) }
+        }
+        Shape::Unnamed(fields) => {
+            let items = fields.iter().map(|field| match field.skip {
+                true => quote! { #value_of::NestedValue::Null },
+                false => into_value(&field.binding),
+            });
+            quote! { #value_of::NestedValue::Array(::std::vec![#(#items),*]) }
+        }
+        Shape::Unit => quote! { #value_of::NestedValue::Null },
+    };
+
+    let (into_body, get_body) = match parse_input(input, "NestedValueOf")? {
+        Input::Struct(shape) => {
+            let pattern = pattern(&quote! { #name }, &shape);
+            let payload = payload(&shape);
+            let arms = bound(fields_of(&shape)).map(|field| {
+                let (ident, key) = (&field.ident, &field.key);
+                quote! {
+                    #key => #value_of::NestedValueOf::get_nested_value(
+                        &#value_of::NestedValue::from(::std::clone::Clone::clone(&self.#ident)),
+                        &keys[1..],
+                    ),
+                }
+            });
+            (
+                quote! {
+                    let #pattern = value;
+                    #payload
+                },
+                quote! {
+                    if keys.is_empty() {
+                        return ::std::option::Option::Some(#value_of::NestedValue::from(::std::clone::Clone::clone(self)));
+                    }
+                    match keys[0] {
+                        #(#arms)*
+                        _ => ::std::option::Option::None,
+                    }
+                },
+            )
+        }
+        Input::Enum(variants) => {
+            let arms = variants.iter().map(|variant| {
+                let (ident, key) = (&variant.ident, &variant.key);
+                let pattern = pattern(&quote! { #name::#ident }, &variant.shape);
+                let value = match (&variant.shape, newtype(&variant.shape)) {
+                    (Shape::Unit, _) => return quote! { #pattern => #value_of::NestedValue::String(#key.to_string()), },
+                    (_, Some(field)) => into_value(&field.binding),
+                    (shape, None) => payload(shape),
+                };
+                quote! {
+                    #pattern => #value_of::NestedValue::Object(::std::collections::HashMap::from([(#key.to_string(), #value)])),
+                }
+            });
+            (
+                quote! {
+                    match value {
+                        #(#arms)*
+                    }
+                },
+                quote! {
+                    #value_of::NestedValueOf::get_nested_value(&#value_of::NestedValue::from(::std::clone::Clone::clone(self)), keys)
+                },
+            )
+        }
+    };
+
+    Ok(quote! {
+        impl ::std::convert::From<#name> for #value_of::NestedValue {
+            fn from(value: #name) -> Self {
+                #into_body
+            }
+        }
+
+        impl #value_of::NestedValueOf for #name {
+            fn get_nested_value(&self, keys: &[&str]) -> ::std::option::Option<#value_of::NestedValue> {
+                #get_body
+            }
+        }
+    })
+}
+
+pub fn derive_nested_value_setter(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let value_of = quote! { ::dioxus_ui::types::nested_value_of };
+    let default = quote! { ::std::default::Default::default() };
+
+    // Expression building the value from a payload (skipped fields are `Default`)
+    let from_payload = |path: &TokenStream, shape: &Shape, payload: &TokenStream| match (shape, newtype(shape)) {
+        (Shape::Unit, _) => quote! { ::std::result::Result::Ok(#path) },
+        (_, Some(_)) => quote! {
+            ::std::result::Result::Ok(#path(#value_of::FromNestedValue::from_nested_value(#payload)?))
+        },
+        (Shape::Named(fields), None) => {
+            let values = fields.iter().map(|field| {
+                let (ident, key) = (&field.ident, &field.key);
+                match field.skip {
+                    true => quote! { #ident: #default },
+                    false => quote! { #ident: #value_of::take_field(&mut object, #key)? },
+                }
+            });
+            quote! {{
+                #[allow(unused_mut, unused_variables)]
+                let mut object = #value_of::into_object(#payload)?;
+                ::std::result::Result::Ok(#path { #(#values),* })
+            }}
+        }
+        (Shape::Unnamed(fields), None) => {
+            let values = fields.iter().map(|field| {
+                let index = field.index;
+                match field.skip {
+                    true => default.clone(),
+                    false => quote! { #value_of::take_item(&mut items, #index)? },
+                }
+            });
+            quote! {{
+                #[allow(unused_mut, unused_variables)]
+                let mut items = #value_of::into_array(#payload)?;
+                ::std::result::Result::Ok(#path(#(#values),*))
+            }}
+        }
+    };
+    let set_field = |field: &NestedField, rest: TokenStream| {
+        let binding = &field.binding;
+        quote! { #value_of::NestedValueSetter::set_nested_value(#binding, #rest, value) }
+    };
+
+    let (from_body, set_body) = match parse_input(input, "NestedValueSetter")? {
+        Input::Struct(shape) => {
+            let pattern = pattern(&quote! { Self }, &shape);
+            let arms = bound(fields_of(&shape)).map(|field| {
+                let (key, set) = (&field.key, set_field(field, quote! { &keys[1..] }));
+                quote! { #key => #set, }
+            });
+            (
+                from_payload(&quote! { Self }, &shape, &quote! { value }),
+                quote! {
+                    if keys.is_empty() {
+                        return #value_of::replace_nested_value(self, value);
+                    }
+                    #[allow(unused_variables)]
+                    let #pattern = self;
+                    match keys[0] {
+                        #(#arms)*
+                        _ => false,
+                    }
+                },
+            )
+        }
+        Input::Enum(variants) => {
+            let from_arms = variants.iter().map(|variant| {
+                let (ident, key) = (&variant.ident, &variant.key);
+                let value = from_payload(&quote! { Self::#ident }, &variant.shape, &quote! { payload });
+                quote! { #key => #value, }
+            });
+            // Paths into another variant can't be set, but the variant itself can be replaced
+            let set_arms = variants.iter().filter_map(|variant| {
+                let (ident, key) = (&variant.ident, &variant.key);
+                let pattern = pattern(&quote! { Self::#ident }, &variant.shape);
+                match (&variant.shape, newtype(&variant.shape)) {
+                    (Shape::Unit, _) => None,
+                    (_, Some(field)) => {
+                        let set = set_field(field, quote! { &keys[1..] });
+                        Some(quote! { #pattern if keys[0] == #key => #set, })
+                    }
+                    (shape, None) => {
+                        let field_arms = bound(fields_of(shape)).map(|field| {
+                            let (key, set) = (&field.key, set_field(field, quote! { &keys[2..] }));
+                            quote! { #key => #set, }
+                        });
+                        Some(quote! {
+                            #pattern if keys[0] == #key && keys.len() > 1 => match keys[1] {
+                                #(#field_arms)*
+                                _ => false,
+                            },
+                        })
+                    }
+                }
+            });
+            (
+                quote! {
+                    #[allow(unused_variables)]
+                    let (name, payload) = #value_of::split_variant(value)?;
+                    match name.as_str() {
+                        #(#from_arms)*
+                        _ => ::std::result::Result::Err(#value_of::NestedValueError::UnknownVariant(name)),
+                    }
+                },
+                quote! {
+                    if keys.is_empty() {
+                        return #value_of::replace_nested_value(self, value);
+                    }
+                    match self {
+                        #(#set_arms)*
+                        _ if keys.len() == 1 => #value_of::replace_nested_value(
+                            self,
+                            #value_of::NestedValue::Object(::std::collections::HashMap::from([(keys[0].to_string(), value)])),
+                        ),
+                        _ => false,
+                    }
+                },
+            )
+        }
+    };
+
+    Ok(quote! {
+        impl #value_of::FromNestedValue for #name {
+            fn from_nested_value(value: #value_of::NestedValue) -> ::std::result::Result<Self, #value_of::NestedValueError> {
+                #from_body
+            }
+        }
+
+        impl ::std::convert::TryFrom<#value_of::NestedValue> for #name {
+            type Error = #value_of::NestedValueError;
+
+            fn try_from(value: #value_of::NestedValue) -> ::std::result::Result<Self, Self::Error> {
+                #value_of::FromNestedValue::from_nested_value(value)
+            }
+        }
+
+        impl #value_of::NestedValueSetter for #name {
+            fn set_nested_value(&mut self, keys: &[&str], value: #value_of::NestedValue) -> bool {
+                #set_body
+            }
+        }
+    })
+}
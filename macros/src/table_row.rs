@@ -0,0 +1,152 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Field, Fields, GenericArgument, LitStr, PathArguments, Type};
+
+const NUMBER_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize",
+    "u8", "u16", "u32", "u64", "u128", "usize",
+    "f32", "f64",
+];
+
+/// Options from the `#[table(...)]` attributes of a field
+#[derive(Default)]
+struct ColumnAttrs {
+    label: Option<String>,
+    is_row_header: bool,
+    is_hidden: bool,
+    width: Option<String>,
+    align: Option<String>,
+    total: bool,
+    skip: bool,
+}
+
+impl ColumnAttrs {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("table")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    attrs.label = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("width") {
+                    attrs.width = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("align") {
+                    attrs.align = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("row_header") {
+                    attrs.is_row_header = true;
+                } else if meta.path.is_ident("hidden") {
+                    attrs.is_hidden = true;
+                } else if meta.path.is_ident("total") {
+                    attrs.total = true;
+                } else if meta.path.is_ident("skip") {
+                    attrs.skip = true;
+                } else {
+                    return Err(meta.error("unknown table attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+/// Inner type of `Option<T>`
+fn get_option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+fn is_number(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path.path.get_ident().is_some_and(|ident| NUMBER_TYPES.contains(&ident.to_string().as_str())),
+        _ => false,
+    }
+}
+
+fn some_or_none<T: quote::ToTokens>(value: Option<T>) -> TokenStream {
+    match value {
+        Some(value) => quote! { ::std::option::Option::Some(#value) },
+        None => quote! { ::std::option::Option::None },
+    }
+}
+
+pub fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(input, "TableRow only supports structs"));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(input, "TableRow only supports structs with named fields"));
+    };
+
+    let table_view = quote! { ::dioxus_ui::components::table_view };
+    let mut columns = Vec::new();
+    for field in fields.named.iter() {
+        let attrs = ColumnAttrs::parse(field)?;
+        if attrs.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named field");
+        let key = ident.to_string();
+        let label = attrs.label.unwrap_or_else(|| key.clone());
+
+        // Option fields show an empty cell for None and sort it first
+        let (value_mapper, inner_type, number_mapper) = match get_option_inner(&field.ty) {
+            Some(inner_type) => (
+                quote! { #table_view::table_row::option_display_mapper(|row: &Self| &row.#ident) },
+                inner_type,
+                quote! { |row: &Self| row.#ident.map(|value| value as f64) },
+            ),
+            None => (
+                quote! { #table_view::table_row::display_mapper(|row: &Self| &row.#ident) },
+                &field.ty,
+                quote! { |row: &Self| ::std::option::Option::Some(row.#ident as f64) },
+            ),
+        };
+        let is_number = is_number(inner_type);
+        let number_mapper = some_or_none(is_number.then(|| quote! { ::std::rc::Rc::new(#number_mapper) }));
+        let align = some_or_none(attrs.align.or_else(|| is_number.then(|| "right".to_string())).map(|align| quote! { #align.to_string() }));
+        let width = some_or_none(attrs.width.map(|width| quote! { #width.to_string() }));
+        let (is_row_header, is_hidden, total) = (attrs.is_row_header, attrs.is_hidden, attrs.total);
+
+        columns.push(quote! {
+            render_map.insert(#key.to_string(), #table_view::get_column_option_map::RenderMapValue::Options(
+                #table_view::get_column_option_map::ColumnOptionArgs {
+                    label: #label.to_string(),
+                    value_mapper: ::std::option::Option::Some(#value_mapper),
+                    is_row_header: ::std::option::Option::Some(#is_row_header),
+                    asc_sorter: ::std::option::Option::Some(#table_view::table_row::ord_sorter(|row: &Self| &row.#ident)),
+                    is_hidden: ::std::option::Option::Some(#is_hidden),
+                    init_column_width: #width,
+                    align: #align,
+                    total: ::std::option::Option::Some(#total),
+                    number_mapper: #number_mapper,
+                    ..::std::default::Default::default()
+                },
+            ));
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics #table_view::table_row::TableRow for #name #type_generics #where_clause {
+            fn render_map() -> #table_view::get_column_option_map::RenderMap<Self> {
+                let mut render_map = #table_view::get_column_option_map::RenderMap::new();
+                #(#columns)*
+                render_map
+            }
+        }
+    })
+}
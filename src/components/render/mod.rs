@@ -1 +1,2 @@
-pub mod use_virtual_scroll;
\ No newline at end of file
+pub mod use_virtual_scroll;
+pub mod use_infinite_rows;
\ No newline at end of file
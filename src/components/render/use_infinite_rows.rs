@@ -0,0 +1,409 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::components::render::use_virtual_scroll::{use_virtual_scroll, UseVirtualScrollParams, UseVirtualScrollResult, VirtualizedDataWithIndex};
+use crate::components::table_view::data_source::{FetchFuture, Page};
+
+/// Async loader of the rows in a range (the page total is the server total)
+pub type InfiniteLoaderFn<T> = Rc<dyn Fn(Range<usize>) -> FetchFuture<T>>;
+
+/// Row of an infinite list
+#[derive(Clone, Debug, PartialEq)]
+pub enum InfiniteRow<T> {
+    Loaded(T),
+    /// Placeholder for a row that has not been fetched yet
+    Skeleton,
+}
+
+impl<T> InfiniteRow<T> {
+    pub fn as_loaded(&self) -> Option<&T> {
+        match self {
+            InfiniteRow::Loaded(item) => Some(item),
+            InfiniteRow::Skeleton => None,
+        }
+    }
+
+    pub fn is_skeleton(&self) -> bool {
+        matches!(self, InfiniteRow::Skeleton)
+    }
+}
+
+/// Rows stored by fixed-size blocks, holding only the fetched ones
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseRows<T> {
+    block_size: usize,
+    blocks: HashMap<usize, Vec<T>>,
+    total: Option<usize>,
+}
+
+impl<T: Clone> SparseRows<T> {
+    pub fn new(block_size: usize) -> Self {
+        Self {
+            block_size: block_size.max(1),
+            blocks: HashMap::new(),
+            total: None,
+        }
+    }
+
+    /// Total reported by the last fetched block (`None` before the first one)
+    pub fn total(&self) -> Option<usize> {
+        self.total
+    }
+
+    /// Number of rows held in memory
+    pub fn loaded_count(&self) -> usize {
+        self.blocks.values().map(Vec::len).sum()
+    }
+
+    pub fn block_of(&self, index: usize) -> usize {
+        index / self.block_size
+    }
+
+    pub fn block_range(&self, block: usize) -> Range<usize> {
+        block * self.block_size..(block + 1) * self.block_size
+    }
+
+    pub fn is_loaded(&self, block: usize) -> bool {
+        self.blocks.contains_key(&block)
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.blocks.get(&self.block_of(index))?.get(index % self.block_size)
+    }
+
+    pub fn get_row(&self, index: usize) -> InfiniteRow<T> {
+        self.get(index).cloned().map_or(InfiniteRow::Skeleton, InfiniteRow::Loaded)
+    }
+
+    /// Store a fetched block and take over its total
+    pub fn insert_block(&mut self, block: usize, page: Page<T>) {
+        let mut items = page.items;
+        items.truncate(self.block_size);
+        self.blocks.insert(block, items);
+        self.total = Some(page.total);
+    }
+
+    /// Blocks overlapping the range that have not been fetched
+    pub fn missing_blocks(&self, range: Range<usize>) -> Vec<usize> {
+        if range.is_empty() {
+            return Vec::new();
+        }
+        (self.block_of(range.start)..=self.block_of(range.end - 1))
+            .filter(|block| !self.is_loaded(*block))
+            .collect()
+    }
+
+    /// End of the run of fetched rows containing the index
+    ///
+    /// Returns the start of the index's block when that block is not fetched.
+    pub fn loaded_end(&self, index: usize) -> usize {
+        let mut block = self.block_of(index);
+        let mut end = self.block_range(block).start;
+        while let Some(items) = self.blocks.get(&block) {
+            end = self.block_range(block).start + items.len();
+            if items.len() < self.block_size {
+                break;
+            }
+            block += 1;
+        }
+        end
+    }
+
+    /// Drop the blocks farthest from `block` until at most `max_blocks` remain
+    pub fn evict_far_from(&mut self, block: usize, max_blocks: usize) {
+        if self.blocks.len() <= max_blocks {
+            return;
+        }
+        let mut loaded: Vec<usize> = self.blocks.keys().copied().collect();
+        loaded.sort_by_key(|loaded_block| loaded_block.abs_diff(block));
+        for far_block in loaded.split_off(max_blocks) {
+            self.blocks.remove(&far_block);
+        }
+    }
+}
+
+/// Parameters for the infinite rows hook
+pub struct UseInfiniteRowsParams<T> {
+    pub loader: InfiniteLoaderFn<T>,
+    /// Number of rows fetched at once (defaults to 50)
+    pub block_size: Option<usize>,
+    /// Rows left before the end of the loaded run at which the next block is fetched
+    /// (defaults to half of `block_size`)
+    pub threshold: Option<usize>,
+    /// Maximum number of blocks held in memory (defaults to unlimited)
+    ///
+    /// Should cover the rendered rows, or visible blocks are fetched again and again.
+    pub max_blocks: Option<usize>,
+    /// Default height for unrendered elements (in pixels)
+    pub default_content_height_px: f64,
+    /// Number of elements to pre-render (defaults to 5)
+    pub over_scan: Option<usize>,
+}
+
+#[derive(Clone)]
+pub struct UseInfiniteRowsResult<T: 'static> {
+    /// Virtual scroll over the server total
+    pub virtual_scroll: UseVirtualScrollResult<InfiniteRow<T>>,
+    /// Rendered rows, with skeletons for rows not fetched yet
+    pub rows: Vec<VirtualizedDataWithIndex<InfiniteRow<T>>>,
+    /// Server total (`None` before the first block is fetched)
+    pub total: Option<usize>,
+    /// Number of rows held in memory
+    pub loaded_count: usize,
+    /// Whether any block is being fetched
+    pub is_loading: bool,
+    /// Drop every fetched block and start over (e.g. after the query changed)
+    pub reset: Rc<RefCell<dyn FnMut()>>,
+}
+
+/// Hook for lazily loading rows of a large remote list while scrolling
+///
+/// `content_length` of the virtual scroll is the server total, while rows are fetched
+/// by blocks when they are rendered or the view nears the end of the loaded run.
+/// Rows are taken from `render_range` since sparse storage has no backing slice.
+pub fn use_infinite_rows<T: Clone + 'static>(params: UseInfiniteRowsParams<T>) -> UseInfiniteRowsResult<T> {
+    let block_size = params.block_size.unwrap_or(50).max(1);
+    let threshold = params.threshold.unwrap_or(block_size / 2);
+    let max_blocks = params.max_blocks;
+    // Loader of the latest render, so that blocks fetched after a change (e.g. of the query) use it
+    let mut loader = use_hook(|| CopyValue::new(params.loader.clone()));
+    loader.set(params.loader);
+
+    let sparse_rows = use_signal(|| SparseRows::<T>::new(block_size));
+    let pending_blocks = use_signal(HashSet::<usize>::new);
+    // Bumped by `reset` so that blocks of a previous generation are discarded
+    let generation = use_signal(|| 0_usize);
+
+    let total = sparse_rows.read().total();
+    let virtual_scroll = use_virtual_scroll::<InfiniteRow<T>>(UseVirtualScrollParams {
+        default_content_height_px: params.default_content_height_px,
+        content_length: total.unwrap_or(0),
+        over_scan: params.over_scan,
+        // Rows keep their index as more are loaded
+        content_ids: None,
+    });
+
+    let wanted_blocks: Vec<usize> = {
+        let sparse_rows = sparse_rows.read();
+        match total {
+            None => Vec::from([0]),
+            Some(total) => {
+                let mut blocks = sparse_rows.missing_blocks(virtual_scroll.render_range.clone());
+                let loaded_end = sparse_rows.loaded_end(virtual_scroll.view_offset);
+                if loaded_end < total && virtual_scroll.is_near_end(loaded_end, threshold) {
+                    let next_block = sparse_rows.block_of(loaded_end);
+                    if !blocks.contains(&next_block) {
+                        blocks.push(next_block);
+                    }
+                }
+                blocks
+            }
+        }
+    };
+    let current_generation = *generation.read();
+
+    use_effect(use_reactive((&wanted_blocks, &current_generation), move |(wanted_blocks, current_generation)| {
+        let mut sparse_rows = sparse_rows;
+        let mut pending_blocks = pending_blocks;
+        for block in wanted_blocks {
+            if pending_blocks.peek().contains(&block) || sparse_rows.peek().is_loaded(block) {
+                continue;
+            }
+            pending_blocks.write().insert(block);
+            let future = (loader.peek())(sparse_rows.peek().block_range(block));
+            spawn(async move {
+                let page = future.await;
+                if *generation.peek() != current_generation {
+                    return;
+                }
+                sparse_rows.with_mut(|sparse_rows| {
+                    sparse_rows.insert_block(block, page);
+                    if let Some(max_blocks) = max_blocks {
+                        sparse_rows.evict_far_from(block, max_blocks);
+                    }
+                });
+                pending_blocks.write().remove(&block);
+            });
+        }
+    }));
+
+    let rows = {
+        let sparse_rows = sparse_rows.read();
+        virtual_scroll.render_range.clone()
+            .map(|index| VirtualizedDataWithIndex {
+                get: sparse_rows.get_row(index),
+                data_index: index,
+            })
+            .collect()
+    };
+
+    let reset = {
+        let mut sparse_rows = sparse_rows;
+        let mut pending_blocks = pending_blocks;
+        let mut generation = generation;
+        Rc::new(RefCell::new(move || {
+            sparse_rows.set(SparseRows::new(block_size));
+            pending_blocks.set(HashSet::new());
+            generation += 1;
+        }))
+    };
+
+    let loaded_count = sparse_rows.read().loaded_count();
+    let is_loading = !pending_blocks.read().is_empty();
+
+    UseInfiniteRowsResult {
+        virtual_scroll,
+        rows,
+        total,
+        loaded_count,
+        is_loading,
+        reset,
+    }
+}
+
+/// 読み込み中の行のプレースホルダー
+#[component]
+pub fn SkeletonRow(
+    height_px: f64,
+    #[props(default)]
+    class: Option<String>,
+) -> Element {
+    let mut class_list = vec!["skeleton-row".to_string()];
+
+    if let Some(additional_class) = &class {
+        class_list.push(additional_class.clone());
+    }
+
+    rsx! {
+        div {
+            class: class_list.join(" "),
+            style: "height: {height_px}px; background: var(--skeleton-color, rgba(127, 127, 127, 0.15));",
+            "aria-busy": true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::render_until_idle;
+
+    fn page(range: Range<usize>, total: usize) -> Page<usize> {
+        Page {
+            items: range.take_while(|index| *index < total).collect(),
+            total,
+        }
+    }
+
+    #[test]
+    fn test_sparse_rows() {
+        let mut rows = SparseRows::<usize>::new(10);
+
+        assert_eq!(rows.missing_blocks(5..25), vec![0, 1, 2]);
+        assert_eq!(rows.get_row(3), InfiniteRow::Skeleton);
+
+        rows.insert_block(1, page(10..20, 25));
+        rows.insert_block(2, page(20..30, 25));
+
+        assert_eq!(rows.total(), Some(25));
+        assert_eq!(rows.loaded_count(), 15);
+        assert_eq!(rows.missing_blocks(5..25), vec![0]);
+        assert_eq!(rows.get_row(12), InfiniteRow::Loaded(12));
+        assert_eq!(rows.loaded_end(3), 0);
+        assert_eq!(rows.loaded_end(12), 25);
+    }
+
+    #[test]
+    fn test_sparse_rows_evict() {
+        let mut rows = SparseRows::<usize>::new(10);
+        for block in 0..5 {
+            rows.insert_block(block, page(rows.block_range(block), 1000));
+        }
+
+        rows.evict_far_from(3, 2);
+
+        assert!(rows.is_loaded(3));
+        assert_eq!(rows.loaded_count(), 20);
+        assert!(!rows.is_loaded(0));
+    }
+
+    #[tokio::test]
+    async fn test_use_infinite_rows() {
+        type Recorded = Rc<RefCell<Vec<(Option<usize>, usize, usize)>>>;
+
+        fn app(recorded: Recorded) -> Element {
+            let infinite_rows = use_infinite_rows(UseInfiniteRowsParams {
+                loader: Rc::new(|range: Range<usize>| -> FetchFuture<usize> {
+                    Box::pin(async move { page(range, 1000) })
+                }),
+                block_size: Some(10),
+                threshold: Some(0),
+                max_blocks: None,
+                default_content_height_px: 35.0,
+                over_scan: Some(5),
+            });
+
+            let skeleton_count = infinite_rows.rows.iter().filter(|row| row.get.is_skeleton()).count();
+            recorded.borrow_mut().push((infinite_rows.total, infinite_rows.rows.len(), skeleton_count));
+
+            rsx! { div {} }
+        }
+
+        let recorded: Recorded = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(app, recorded.clone());
+        dom.rebuild_in_place();
+        render_until_idle(&mut dom).await;
+
+        let recorded = recorded.borrow();
+        assert_eq!(recorded.first(), Some(&(None, 0, 0)));
+        // The first block reveals the total; the second rendered block is fetched next
+        assert!(recorded.contains(&(Some(1000), 15, 5)));
+        assert_eq!(recorded.last(), Some(&(Some(1000), 15, 0)));
+    }
+
+    #[tokio::test]
+    async fn test_use_infinite_rows_reset_uses_latest_loader() {
+        // Offset of the loaded values and the result of the latest render
+        type Latest = Rc<RefCell<Option<(Signal<usize>, UseInfiniteRowsResult<usize>)>>>;
+
+        fn app(latest: Latest) -> Element {
+            let offset = use_signal(|| 0_usize);
+            let value_offset = offset();
+            let infinite_rows = use_infinite_rows(UseInfiniteRowsParams {
+                loader: Rc::new(move |range: Range<usize>| -> FetchFuture<usize> {
+                    Box::pin(async move {
+                        let Page { items, total } = page(range, 1000);
+                        Page { items: items.into_iter().map(|item| item + value_offset).collect(), total }
+                    })
+                }),
+                block_size: Some(10),
+                threshold: Some(0),
+                max_blocks: None,
+                default_content_height_px: 35.0,
+                over_scan: Some(5),
+            });
+            *latest.borrow_mut() = Some((offset, infinite_rows));
+
+            rsx! { div {} }
+        }
+
+        let latest: Latest = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(app, latest.clone());
+        dom.rebuild_in_place();
+        render_until_idle(&mut dom).await;
+        let first_row = || latest.borrow().as_ref().unwrap().1.rows[0].get.clone();
+        assert_eq!(first_row(), InfiniteRow::Loaded(0));
+
+        // A new loader (e.g. for another query) followed by a reset
+        let (mut offset, infinite_rows) = latest.borrow().clone().unwrap();
+        dom.in_runtime(|| {
+            offset.set(100);
+            infinite_rows.reset.borrow_mut()();
+        });
+        render_until_idle(&mut dom).await;
+        assert_eq!(first_row(), InfiniteRow::Loaded(100));
+    }
+}
@@ -2,8 +2,9 @@ use dioxus::prelude::*;
 use std::rc::Rc;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::ops::Range;
 
-use crate::function::range::{range, range_from};
+use crate::function::fenwick_tree::FenwickTree;
 use crate::function::signal::use_debounce::{use_debounce, DebounceProps};
 
 /// Parameters for the virtual scroll hook
@@ -14,6 +15,10 @@ pub struct UseVirtualScrollParams {
     pub content_length: usize,
     /// Number of elements to pre-render (defaults to 5)
     pub over_scan: Option<usize>,
+    /// IDs of the elements in order (defaults to their indices)
+    ///
+    /// Measured heights follow the IDs when the order changes.
+    pub content_ids: Option<Vec<String>>,
 }
 
 /// Props to be passed to the VirtualScroll component
@@ -34,14 +39,147 @@ pub struct VirtualizedDataWithIndex<T> {
     pub data_index: usize,
 }
 
+/// Heights of the elements, measured or estimated
+///
+/// Heights are measured per element ID, and unmeasured elements take the estimate
+/// (the first measured height, or the default).
+/// Offsets are prefix sums of the differences from the estimate in a Fenwick tree laid out
+/// in the current order, so that lookups take O(log n) regardless of the number of elements.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowHeights {
+    estimate_px: f64,
+    is_estimate_measured: bool,
+    measured: HashMap<String, f64>,
+    /// IDs in order with the index of each, or none when the IDs are the indices
+    order: Option<(Vec<String>, HashMap<String, usize>)>,
+    deltas: FenwickTree,
+}
+
+impl RowHeights {
+    pub fn new(estimate_px: f64) -> Self {
+        Self {
+            estimate_px,
+            is_estimate_measured: false,
+            measured: HashMap::new(),
+            order: None,
+            deltas: FenwickTree::new(0),
+        }
+    }
+
+    /// Height used for unmeasured elements
+    pub fn estimate_px(&self) -> f64 {
+        self.estimate_px
+    }
+
+    /// ID of the element at an index in the current order
+    pub fn id_at(&self, index: usize) -> Option<String> {
+        match &self.order {
+            Some((ids, _)) => ids.get(index).cloned(),
+            None => Some(index.to_string()),
+        }
+    }
+
+    fn index_of(&self, id: &str) -> Option<usize> {
+        match &self.order {
+            Some((_, indices)) => indices.get(id).copied(),
+            None => id.parse().ok(),
+        }
+    }
+
+    pub fn measured_px(&self, id: &str) -> Option<f64> {
+        self.measured.get(id).copied()
+    }
+
+    pub fn height_px(&self, index: usize) -> f64 {
+        self.id_at(index)
+            .and_then(|id| self.measured_px(&id))
+            .unwrap_or(self.estimate_px)
+    }
+
+    /// Sum of the heights before the index
+    pub fn offset_px(&self, index: usize) -> f64 {
+        index as f64 * self.estimate_px + self.deltas.prefix_sum(index)
+    }
+
+    /// Smallest index (up to `len`) whose offset is at least `px`
+    pub fn index_at_px(&self, px: f64, len: usize) -> usize {
+        if px <= 0.0 {
+            return 0;
+        }
+        let measured_len = self.deltas.len().min(len);
+        let below = self.deltas.search(|end, delta| end <= measured_len && end as f64 * self.estimate_px + delta < px);
+        if below < measured_len || measured_len == len {
+            return (below + 1).min(len);
+        }
+        // Beyond the tree every element has the estimated height
+        if self.estimate_px <= 0.0 {
+            return len;
+        }
+        let rest_px = px - self.offset_px(measured_len);
+        (measured_len + (rest_px / self.estimate_px).ceil() as usize).min(len)
+    }
+
+    /// Lay the measured heights out in a new order of the IDs (`None` for the indices)
+    pub fn set_order(&mut self, ids: Option<Vec<String>>) {
+        if self.order.as_ref().map(|(ids, _)| ids) == ids.as_ref() {
+            return;
+        }
+        self.order = ids.map(|ids| {
+            let indices = ids.iter().enumerate().map(|(index, id)| (id.clone(), index)).collect();
+            (ids, indices)
+        });
+        let len = match &self.order {
+            Some((ids, _)) => ids.len(),
+            None => self.measured.keys().filter_map(|id| self.index_of(id)).max().map_or(0, |index| index + 1),
+        };
+        self.rebuild(len);
+    }
+
+    /// Record the measured height of an element
+    pub fn set(&mut self, id: &str, height_px: f64) {
+        let height_px = height_px.max(0.0);
+        let prev_delta = self.measured.insert(id.to_string(), height_px)
+            .map(|prev| prev - self.estimate_px)
+            .unwrap_or(0.0);
+        let index = self.index_of(id);
+
+        if !self.is_estimate_measured && height_px > 0.0 {
+            // The first real height is a better estimate than the default
+            self.estimate_px = height_px;
+            self.is_estimate_measured = true;
+            self.rebuild(self.deltas.len().max(index.map_or(0, |index| index + 1)));
+            return;
+        }
+        match index {
+            Some(index) if index < self.deltas.len() => self.deltas.add(index, height_px - self.estimate_px - prev_delta),
+            Some(index) => self.rebuild((index + 1).max(self.deltas.len() * 2)),
+            // Not in the current order, laid out when it comes back
+            None => {}
+        }
+    }
+
+    fn rebuild(&mut self, len: usize) {
+        let mut deltas = FenwickTree::new(len);
+        for (id, height_px) in &self.measured {
+            if let Some(index) = self.index_of(id).filter(|index| *index < len) {
+                deltas.add(index, height_px - self.estimate_px);
+            }
+        }
+        self.deltas = deltas;
+    }
+}
+
 // Type aliases for function types
-type SetRenderCacheFn = Rc<dyn Fn(usize) -> Rc<RefCell<dyn FnMut(Option<String>)>>>;
-type SetViewCacheFn = Rc<RefCell<dyn FnMut(Option<String>)>>;
+type SetMountedFn = Rc<RefCell<dyn FnMut(Option<Rc<MountedData>>)>>;
+type SetRenderCacheFn = Rc<dyn Fn(usize) -> SetMountedFn>;
+type SetRenderHeightFn = Rc<dyn Fn(usize) -> Rc<RefCell<dyn FnMut(f64)>>>;
+type SetViewCacheFn = SetMountedFn;
 type GetVirtualizedFn<T> = Rc<dyn Fn(&[T]) -> Vec<T>>;
 type GetVirtualizedWithIndexFn<T> = Rc<dyn Fn(&[T]) -> Vec<VirtualizedDataWithIndex<T>>>;
 type GetOffsetPxByIndexFn = Rc<dyn Fn(usize) -> f64>;
 
 /// Result type for the virtual scroll hook
+#[derive(Clone)]
 pub struct UseVirtualScrollResult<T> {
     /// Props to be passed to the VirtualScroll component
     pub props: VirtualScrollProps,
@@ -51,22 +189,36 @@ pub struct UseVirtualScrollResult<T> {
     pub get_virtualized: GetVirtualizedFn<T>,
     /// Get virtualized data with original indices
     pub get_virtualized_with_index: GetVirtualizedWithIndexFn<T>,
-    /// Set render cache for a specific index
+    /// Measure the element at an index from its mounted data (for `onmounted`)
     pub set_render_cache: SetRenderCacheFn,
-    /// Set view cache reference
+    /// Set the height of the element at an index (for `onresize`)
+    pub set_render_height: SetRenderHeightFn,
+    /// Measure the scroll container from its mounted data (for `onmounted`)
     pub set_view_cache: SetViewCacheFn,
+    /// Set the height of the scroll container (for `onresize`)
+    pub set_view_height: Rc<RefCell<dyn FnMut(f64)>>,
     /// Current view offset
     pub view_offset: usize,
     /// Current view limit
     pub view_limit: usize,
+    /// Indices rendered by `get_virtualized` (view plus over scan, within `content_length`)
+    pub render_range: Range<usize>,
     /// Get offset pixels by index
     pub get_offset_px_by_index: GetOffsetPxByIndexFn,
 }
 
+impl<T> UseVirtualScrollResult<T> {
+    /// Whether the view is within `threshold` elements of the end of the loaded content
+    pub fn is_near_end(&self, loaded_length: usize, threshold: usize) -> bool {
+        self.view_offset + self.view_limit + threshold >= loaded_length
+    }
+}
+
 /// Hook for virtual scrolling functionality
 /// 
 /// This hook provides virtual scrolling capability that only renders visible elements
 /// within a scroll container, improving performance for large datasets.
+/// Element and container heights are measured when mounted and whenever they are resized.
 /// 
 /// # Arguments
 /// 
@@ -87,6 +239,7 @@ pub struct UseVirtualScrollResult<T> {
 ///         default_content_height_px: 35.0,
 ///         content_length: data.len(),
 ///         over_scan: Some(5),
+///         content_ids: None,
 ///     });
 ///     
 ///     let virtualized_data = (virtual_scroll.get_virtualized_with_index)(&data);
@@ -94,6 +247,18 @@ pub struct UseVirtualScrollResult<T> {
 ///     rsx! {
 ///         div {
 ///             style: "overflow: auto; height: 400px; display: grid;",
+///             onmounted: {
+///                 let set_view_cache = virtual_scroll.set_view_cache.clone();
+///                 move |evt: MountedEvent| set_view_cache.borrow_mut()(Some(evt.data()))
+///             },
+///             onresize: {
+///                 let set_view_height = virtual_scroll.set_view_height.clone();
+///                 move |evt: ResizeEvent| {
+///                     if let Ok(size) = evt.get_content_box_size() {
+///                         set_view_height.borrow_mut()(size.height);
+///                     }
+///                 }
+///             },
 ///             onscroll: move |evt| {
 ///                 let scroll_top = evt.data.scroll_top() as f64;
 ///                 virtual_scroll.set_offset_on_scroll.borrow_mut()(scroll_top);
@@ -104,7 +269,14 @@ pub struct UseVirtualScrollResult<T> {
 ///             for item in virtualized_data {
 ///                 div {
 ///                     key: "{item.data_index}",
-///                     style: "height: 35px;",
+///                     onresize: {
+///                         let set_height = (virtual_scroll.set_render_height)(item.data_index);
+///                         move |evt: ResizeEvent| {
+///                             if let Ok(size) = evt.get_border_box_size() {
+///                                 set_height.borrow_mut()(size.height);
+///                             }
+///                         }
+///                     },
 ///                     "{item.get}"
 ///                 }
 ///             }
@@ -119,81 +291,39 @@ pub fn use_virtual_scroll<T: Clone + 'static>(params: UseVirtualScrollParams) ->
     let over_scan = params.over_scan.unwrap_or(5);
     let debounce = use_debounce(0);
     
-    // Height map for tracking actual rendered element heights
-    let height_map = use_signal(HashMap::<usize, f64>::new);
+    // Measured heights of rendered elements, laid out in the current order
+    // (not a signal, since the order is updated while rendering)
+    let mut row_heights = use_hook(|| CopyValue::new(RowHeights::new(params.default_content_height_px)));
+    row_heights.write().set_order(params.content_ids);
+    // Bumped by new measurements to render with them
+    let measured_count = use_signal(|| 0_usize);
     
-    // View container reference (element ID for tracking)
-    let view_ref = use_signal(|| None::<String>);
+    // Measured height of the scroll container
+    let view_height_px = use_signal(|| None::<f64>);
     
     // Current scroll position
     let scrolled_px = use_signal(|| 0.0_f64);
     
-    // Get sample content height (first available height or default)
-    let sample_content_height_px = {
-        let height_map_read = height_map.read();
-        height_map_read.values()
-            .find(|&&height| height > 0.0)
-            .copied()
-            .unwrap_or(params.default_content_height_px)
-    };
+    let content_length = params.content_length;
     
-    // Calculate view offset
-    let raw_offsets = {
-        let mut accumulator = AccumulatorState { px: 0.0, count: 0 };
-        let height_map_read = height_map.read();
-        let current_scroll = *scrolled_px.read();
+    let (view_offset, view_limit, before_height_px, max_height_px) = {
+        // Subscribe to new measurements
+        measured_count.read();
+        let row_heights = row_heights.read();
+        // Calculate view offset (elements scrolled past)
+        let view_offset = row_heights.index_at_px(*scrolled_px.read(), content_length);
         
-        for index in range(params.content_length) {
-            let height = height_map_read.get(&index).copied().unwrap_or(sample_content_height_px);
-            if accumulator.px < current_scroll {
-                accumulator.px += height;
-                accumulator.count += 1;
-            } else {
-                break;
-            }
-        }
-        accumulator
-    };
-    let view_offset = raw_offsets.count;
-    let render_offset = raw_offsets.count.saturating_sub(over_scan);
-    
-    // Calculate view limit  
-    let raw_limits = {
-        let mut accumulator = AccumulatorState { px: 0.0, count: 0 };
-        let height_map_read = height_map.read();
-        // Assume a default view height if we don't have the actual container size
-        let view_height = sample_content_height_px * 10.0; // Mock view height
+        // Calculate view limit (elements filling the container)
+        // Assume ten elements until the container has been measured
+        let view_height = view_height_px.read().unwrap_or(row_heights.estimate_px() * 10.0);
+        let view_end = row_heights.index_at_px(row_heights.offset_px(view_offset) + view_height, content_length);
         
-        for index in range_from(params.content_length.saturating_sub(raw_offsets.count), raw_offsets.count) {
-            let height = height_map_read.get(&index).copied().unwrap_or(sample_content_height_px);
-            if accumulator.px < view_height {
-                accumulator.px += height;
-                accumulator.count += 1;
-            } else {
-                break;
-            }
-        }
-        accumulator
-    };
-    let view_limit = raw_limits.count;
-    let render_limit = raw_limits.count + over_scan;
-    
-    // Calculate heights
-    let before_height_px = {
-        let height_map_read = height_map.read();
-        range(render_offset)
-            .iter()
-            .map(|&index| height_map_read.get(&index).copied().unwrap_or(sample_content_height_px))
-            .sum()
-    };
-    
-    let max_height_px = {
-        let height_map_read = height_map.read();
-        range(params.content_length)
-            .iter()
-            .map(|&index| height_map_read.get(&index).copied().unwrap_or(sample_content_height_px))
-            .sum()
+        // Calculate heights
+        let before_height_px = row_heights.offset_px(view_offset.saturating_sub(over_scan));
+        (view_offset, view_end - view_offset, before_height_px, row_heights.offset_px(content_length))
     };
+    let render_offset = view_offset.saturating_sub(over_scan);
+    let render_limit = view_limit + over_scan;
     
     // Set offset on scroll function
     let set_offset_on_scroll_fn = {
@@ -242,40 +372,75 @@ pub fn use_virtual_scroll<T: Clone + 'static>(params: UseVirtualScrollParams) ->
         })
     };
     
+    // Set render height function
+    let set_render_height_fn: SetRenderHeightFn = {
+        Rc::new(move |index: usize| -> Rc<RefCell<dyn FnMut(f64)>> {
+            let mut measured_count = measured_count;
+            // The element rendered at the index now, even if measured after the order changed
+            let id = row_heights.peek().id_at(index);
+            Rc::new(RefCell::new(move |height_px: f64| {
+                let Some(id) = &id else {
+                    return;
+                };
+                // Skip unchanged heights to avoid rerendering
+                if row_heights.peek().measured_px(id) == Some(height_px) {
+                    return;
+                }
+                row_heights.write().set(id, height_px);
+                measured_count += 1;
+            }))
+        })
+    };
+    
     // Set render cache function
     let set_render_cache_fn = {
-        let default_height = params.default_content_height_px;
-        Rc::new(move |index: usize| -> Rc<RefCell<dyn FnMut(Option<String>)>> {
-            let mut height_map = height_map;
-            Rc::new(RefCell::new(move |element_id: Option<String>| {
-                if let Some(_id) = element_id {
-                    // In a real implementation, you would get the element's offsetHeight
-                    // For now, we'll use a mock height calculation
-                    let mock_height = default_height;
-                    height_map.with_mut(|map| {
-                        map.insert(index, mock_height);
-                    });
-                }
+        let set_render_height = set_render_height_fn.clone();
+        Rc::new(move |index: usize| -> SetMountedFn {
+            let set_height = set_render_height(index);
+            Rc::new(RefCell::new(move |element: Option<Rc<MountedData>>| {
+                let Some(element) = element else {
+                    return;
+                };
+                let set_height = set_height.clone();
+                spawn(async move {
+                    if let Ok(rect) = element.get_client_rect().await {
+                        set_height.borrow_mut()(rect.height());
+                    }
+                });
             }))
         })
     };
     
+    // Set view height function
+    let set_view_height_fn = {
+        let mut view_height_px = view_height_px;
+        Rc::new(RefCell::new(move |height_px: f64| {
+            if *view_height_px.peek() != Some(height_px) {
+                view_height_px.set(Some(height_px));
+            }
+        }))
+    };
+    
     // Set view cache function
     let set_view_cache_fn = {
-        let mut view_ref = view_ref;
-        Rc::new(RefCell::new(move |element_id: Option<String>| {
-            view_ref.set(element_id);
+        let set_view_height = set_view_height_fn.clone();
+        Rc::new(RefCell::new(move |element: Option<Rc<MountedData>>| {
+            let Some(element) = element else {
+                return;
+            };
+            let set_view_height = set_view_height.clone();
+            spawn(async move {
+                if let Ok(rect) = element.get_client_rect().await {
+                    set_view_height.borrow_mut()(rect.height());
+                }
+            });
         }))
     };
     
     // Get offset pixels by index function
     let get_offset_px_by_index_fn = {
         Rc::new(move |index: usize| -> f64 {
-            let height_map_read = height_map.read();
-            range(index.max(0))
-                .iter()
-                .map(|&i| height_map_read.get(&i).copied().unwrap_or(sample_content_height_px))
-                .sum()
+            row_heights.read().offset_px(index)
         })
     };
     
@@ -288,23 +453,20 @@ pub fn use_virtual_scroll<T: Clone + 'static>(params: UseVirtualScrollParams) ->
         get_virtualized: get_virtualized_fn,
         get_virtualized_with_index: get_virtualized_with_index_fn,
         set_render_cache: set_render_cache_fn,
+        set_render_height: set_render_height_fn,
         set_view_cache: set_view_cache_fn,
+        set_view_height: set_view_height_fn,
         view_offset,
         view_limit,
+        render_range: render_offset.min(content_length)..(render_offset + render_limit).min(content_length),
         get_offset_px_by_index: get_offset_px_by_index_fn,
     }
 }
 
-/// Helper struct for accumulating scroll calculations
-#[derive(Debug, Clone)]
-struct AccumulatorState {
-    px: f64,
-    count: usize,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::render_until_idle;
     
     #[test]
     fn test_use_virtual_scroll_basic() {
@@ -315,12 +477,16 @@ mod tests {
                 default_content_height_px: 35.0,
                 content_length: 100,
                 over_scan: Some(5),
+                content_ids: None,
             });
             
             // Test that the virtual scroll structure is created
             assert!(virtual_scroll.props.before_height_px >= 0.0);
             assert!(virtual_scroll.props.max_height_px > 0.0);
             assert_eq!(virtual_scroll.view_offset, 0);
+            assert_eq!(virtual_scroll.render_range, 0..15);
+            assert!(!virtual_scroll.is_near_end(100, 10));
+            assert!(virtual_scroll.is_near_end(20, 10));
             
             rsx! { div { "Virtual scroll test" } }
         });
@@ -363,6 +529,7 @@ mod tests {
                 default_content_height_px: 35.0,
                 content_length: 0,
                 over_scan: Some(5),
+                content_ids: None,
             });
             
             let data: Vec<String> = vec![];
@@ -387,6 +554,7 @@ mod tests {
                 default_content_height_px: 35.0,
                 content_length: 10,
                 over_scan: Some(2),
+                content_ids: None,
             });
             
             let data: Vec<String> = (0..10).map(|i| format!("Item {}", i)).collect();
@@ -403,13 +571,93 @@ mod tests {
     }
     
     #[test]
-    fn test_accumulator_state() {
-        let state = AccumulatorState { px: 100.0, count: 5 };
-        assert_eq!(state.px, 100.0);
-        assert_eq!(state.count, 5);
+    fn test_row_heights() {
+        let mut row_heights = RowHeights::new(35.0);
+        assert_eq!(row_heights.offset_px(4), 140.0);
+        
+        // The first measurement becomes the estimate of the others
+        row_heights.set("1", 20.0);
+        row_heights.set("2", 50.0);
+        assert_eq!(row_heights.estimate_px(), 20.0);
+        assert_eq!(row_heights.height_px(3), 20.0);
+        assert_eq!(row_heights.offset_px(4), 110.0);
+        
+        row_heights.set("2", 30.0);
+        assert_eq!(row_heights.offset_px(100), 2010.0);
+        
+        // Offsets: 0, 20, 40, 70, 90, ...
+        assert_eq!(row_heights.index_at_px(0.0, 100), 0);
+        assert_eq!(row_heights.index_at_px(41.0, 100), 3);
+        assert_eq!(row_heights.index_at_px(70.0, 100), 3);
+        assert_eq!(row_heights.index_at_px(1000.0, 100), 50);
+        assert_eq!(row_heights.index_at_px(1_000_000.0, 100), 100);
+    }
+    
+    #[test]
+    fn test_row_heights_follow_ids() {
+        let ids = |ids: &[&str]| Some(ids.iter().map(|id| id.to_string()).collect::<Vec<_>>());
+        let mut row_heights = RowHeights::new(10.0);
+        row_heights.set_order(ids(&["a", "b", "c"]));
+        row_heights.set("a", 10.0);
+        row_heights.set("b", 30.0);
+        assert_eq!(row_heights.offset_px(3), 50.0);
+        
+        row_heights.set_order(ids(&["c", "b", "a"]));
+        assert_eq!(row_heights.height_px(1), 30.0);
+        assert_eq!(row_heights.offset_px(1), 10.0);
+        assert_eq!(row_heights.offset_px(2), 40.0);
+        
+        // Heights of rows out of the order are kept for when they come back
+        row_heights.set_order(ids(&["c", "a"]));
+        assert_eq!(row_heights.offset_px(2), 20.0);
+        row_heights.set("b", 20.0);
+        row_heights.set_order(ids(&["b", "c"]));
+        assert_eq!(row_heights.offset_px(2), 30.0);
+    }
+    
+    #[tokio::test]
+    async fn test_use_virtual_scroll_heights_follow_content_ids() {
+        use dioxus::prelude::*;
+        
+        // IDs signal and the result of the latest render
+        type Latest = Rc<RefCell<Option<(Signal<Vec<String>>, UseVirtualScrollResult<String>)>>>;
+        
+        fn app(latest: Latest) -> Element {
+            let ids = use_signal(|| (0..10).map(|index| format!("item-{}", index)).collect::<Vec<_>>());
+            let virtual_scroll = use_virtual_scroll::<String>(UseVirtualScrollParams {
+                default_content_height_px: 35.0,
+                content_length: 10,
+                over_scan: Some(2),
+                content_ids: Some(ids()),
+            });
+            *latest.borrow_mut() = Some((ids, virtual_scroll));
+            
+            rsx! { div {} }
+        }
+        
+        let latest: Latest = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(app, latest.clone());
+        dom.rebuild_in_place();
+        let virtual_scroll = || latest.borrow().as_ref().unwrap().1.clone();
+        
+        let (mut ids, first_render) = latest.borrow().clone().unwrap();
+        dom.in_runtime(|| {
+            (first_render.set_render_height)(0).borrow_mut()(40.0);
+            (first_render.set_render_height)(1).borrow_mut()(100.0);
+        });
+        render_until_idle(&mut dom).await;
+        assert_eq!(virtual_scroll().props.max_height_px, 460.0);
+        let set_second_height = dom.in_runtime(|| (first_render.set_render_height)(1));
+        
+        // Reversed, e.g. by sorting: the tall row moves to the second to last index
+        dom.in_runtime(|| ids.with_mut(|ids| ids.reverse()));
+        render_until_idle(&mut dom).await;
+        assert_eq!(virtual_scroll().props.max_height_px, 460.0);
+        assert_eq!((virtual_scroll().get_offset_px_by_index)(9), 420.0);
         
-        let cloned = state.clone();
-        assert_eq!(cloned.px, 100.0);
-        assert_eq!(cloned.count, 5);
+        // Measurements started before the change still go to the row they were made for
+        dom.in_runtime(|| set_second_height.borrow_mut()(60.0));
+        render_until_idle(&mut dom).await;
+        assert_eq!((virtual_scroll().get_offset_px_by_index)(9), 380.0);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,228 @@
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+use crate::function::input_event_util::{is_arrow_left_actionable, is_arrow_right_actionable};
+
+/// Renders a custom editor from the editor props
+pub type CustomEditorFn = Rc<dyn Fn(CellEditorProps) -> Element>;
+
+/// Editor of an editable column
+///
+/// Editors edit the cell text, which `on_cell_edit` receives on commit.
+#[derive(Clone)]
+pub enum EditorKind {
+    Text,
+    /// Text parsed as a number on commit
+    Number,
+    /// One of the options
+    Select(Vec<String>),
+    /// `"true"` or `"false"`
+    Checkbox,
+    /// ISO 8601 date (`YYYY-MM-DD`)
+    Date,
+    Custom(CustomEditorFn),
+}
+
+impl PartialEq for EditorKind {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (EditorKind::Select(prev), EditorKind::Select(next)) => prev == next,
+            (EditorKind::Custom(prev), EditorKind::Custom(next)) => Rc::ptr_eq(prev, next),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl EditorKind {
+    /// Value to commit from the edited text, or why it cannot be committed
+    pub fn parse(&self, value: &str) -> Result<String, String> {
+        match self {
+            EditorKind::Number => {
+                let value = value.trim();
+                value.parse::<f64>()
+                    .map(|_| value.to_string())
+                    .map_err(|_| format!("\"{value}\" is not a number"))
+            }
+            EditorKind::Checkbox => Ok((value == "true").to_string()),
+            EditorKind::Date => {
+                let value = value.trim();
+                is_date(value)
+                    .then(|| value.to_string())
+                    .ok_or_else(|| format!("\"{value}\" is not a date (YYYY-MM-DD)"))
+            }
+            _ => Ok(value.to_string()),
+        }
+    }
+}
+
+/// Whether the text is an existing date in the `YYYY-MM-DD` format
+fn is_date(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+    let [year, month, day] = parts[..] else {
+        return false;
+    };
+    let is_number = |part: &str, len: usize| {
+        part.len() == len && part.bytes().all(|byte| byte.is_ascii_digit())
+    };
+    if !(is_number(year, 4) && is_number(month, 2) && is_number(day, 2)) {
+        return false;
+    }
+    let (year, month, day): (u32, u32, u32) = (
+        year.parse().unwrap_or(0),
+        month.parse().unwrap_or(0),
+        day.parse().unwrap_or(0),
+    );
+    let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days_in_month).contains(&day)
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CellEditorProps {
+    pub kind: EditorKind,
+    /// Text being edited
+    pub value: String,
+    /// Why the value could not be committed
+    #[props(default)]
+    pub error: Option<String>,
+    pub on_input: EventHandler<String>,
+    /// Called on commit with the number of columns to move the focus by (0 to stay)
+    pub on_commit: EventHandler<isize>,
+    pub on_cancel: EventHandler<()>,
+}
+
+/// Editor of a cell
+///
+/// Enter / Tab commit and Esc cancels. ArrowLeft / ArrowRight commit and move to the next cell
+/// only when the cursor is at the edge of the text.
+#[component]
+pub fn CellEditor(props: CellEditorProps) -> Element {
+    let (on_input, on_commit, on_cancel) = (props.on_input, props.on_commit, props.on_cancel);
+
+    let handle_key_down = move |event: KeyboardEvent| {
+        // Keep the keys from the table navigation
+        event.stop_propagation();
+        if event.data().is_composing() {
+            return;
+        }
+        match event.key() {
+            Key::Enter => {
+                event.prevent_default();
+                on_commit.call(0);
+            }
+            Key::Tab => {
+                event.prevent_default();
+                on_commit.call(if event.modifiers().shift() { -1 } else { 1 });
+            }
+            Key::Escape => {
+                event.prevent_default();
+                on_cancel.call(());
+            }
+            Key::ArrowLeft if is_arrow_left_actionable(&event) => {
+                event.prevent_default();
+                on_commit.call(-1);
+            }
+            Key::ArrowRight if is_arrow_right_actionable(&event) => {
+                event.prevent_default();
+                on_commit.call(1);
+            }
+            _ => {}
+        }
+    };
+
+    let focus_on_mount = |event: MountedEvent| {
+        spawn(async move {
+            let _ = event.data().set_focus(true).await;
+        });
+    };
+
+    let editor = match &props.kind {
+        EditorKind::Text | EditorKind::Number | EditorKind::Date => {
+            let input_type = match props.kind {
+                EditorKind::Number => "number",
+                EditorKind::Date => "date",
+                _ => "text",
+            };
+            rsx! {
+                input {
+                    r#type: input_type,
+                    value: "{props.value}",
+                    "aria-invalid": props.error.is_some(),
+                    onmounted: focus_on_mount,
+                    oninput: move |event: FormEvent| on_input.call(event.value()),
+                }
+            }
+        }
+        EditorKind::Select(options) => rsx! {
+            select {
+                "aria-invalid": props.error.is_some(),
+                onmounted: focus_on_mount,
+                oninput: move |event: FormEvent| on_input.call(event.value()),
+                for option in options.iter() {
+                    option {
+                        key: "{option}",
+                        value: "{option}",
+                        selected: *option == props.value,
+                        "{option}"
+                    }
+                }
+            }
+        },
+        EditorKind::Checkbox => rsx! {
+            input {
+                r#type: "checkbox",
+                checked: props.value == "true",
+                onmounted: focus_on_mount,
+                oninput: move |event: FormEvent| on_input.call(event.checked().to_string()),
+            }
+        },
+        EditorKind::Custom(render) => render(props.clone()),
+    };
+
+    rsx! {
+        div {
+            class: "cell-editor",
+            onkeydown: handle_key_down,
+            // Clicks in the editor don't move the focused cell
+            onclick: |event| event.stop_propagation(),
+            {editor}
+            if let Some(error) = &props.error {
+                div {
+                    class: "cell-editor-error",
+                    role: "alert",
+                    style: "color: var(--error-color, red); font-size: 0.85em; white-space: normal;",
+                    "{error}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_kind_parse() {
+        assert_eq!(EditorKind::Number.parse(" 1.5 "), Ok("1.5".to_string()));
+        assert!(EditorKind::Number.parse("abc").is_err());
+        assert_eq!(EditorKind::Checkbox.parse("true"), Ok("true".to_string()));
+        assert_eq!(EditorKind::Checkbox.parse(""), Ok("false".to_string()));
+        assert_eq!(EditorKind::Text.parse(" a "), Ok(" a ".to_string()));
+        assert_eq!(
+            EditorKind::Date.parse("2024-02-29"),
+            Ok("2024-02-29".to_string())
+        );
+        assert!(EditorKind::Date.parse("2023-02-29").is_err());
+        assert!(EditorKind::Date.parse("2024-13-01").is_err());
+        assert!(EditorKind::Date.parse("2024-1-01").is_err());
+        assert!(EditorKind::Date.parse("tomorrow").is_err());
+        assert!(EditorKind::Select(vec!["a".to_string()]) == EditorKind::Select(vec!["a".to_string()]));
+    }
+}
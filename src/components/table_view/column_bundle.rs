@@ -64,6 +64,14 @@ pub struct ColumnBundleProps {
     #[props(default = false)]
     is_hidden: bool,
     
+    /// マウント時のハンドラ（行の高さの計測用）
+    #[props(default)]
+    onmounted: Option<EventHandler<MountedEvent>>,
+    
+    /// リサイズ時のハンドラ（行の高さの計測用）
+    #[props(default)]
+    onresize: Option<EventHandler<ResizeEvent>>,
+    
     /// その他のHTML属性
     #[props(extends = div)]
     attributes: Vec<Attribute>,
@@ -101,16 +109,51 @@ pub fn ColumnBundle(props: ColumnBundleProps) -> Element {
     
     let class_name = classes.join(" ");
     
+    // 計測しない場合はリスナー（ResizeObserver）を付けない
+    if props.onmounted.is_none() && props.onresize.is_none() {
+        return rsx! {
+            div {
+                class: "{class_name}",
+                style: "{combined_style}",
+                ..props.attributes,
+                {props.children}
+            }
+        };
+    }
+    
+    let (onmounted, onresize) = (props.onmounted, props.onresize);
+    
     rsx! {
         div {
             class: "{class_name}",
             style: "{combined_style}",
+            onmounted: move |event| {
+                if let Some(onmounted) = onmounted {
+                    onmounted.call(event);
+                }
+            },
+            onresize: move |event| {
+                if let Some(onresize) = onresize {
+                    onresize.call(event);
+                }
+            },
             ..props.attributes,
             {props.children}
         }
     }
 }
 
+/// 仮想化で描画しないカラムの分を埋めるセル
+#[component]
+pub fn ColumnSpacer(span: usize) -> Element {
+    rsx! {
+        div {
+            class: "ColumnSpacer",
+            style: "grid-column: span {span};",
+        }
+    }
+}
+
 // スタイル定義
 pub const COLUMN_BUNDLE_STYLES: &str = r#"
     .ColumnBundle {
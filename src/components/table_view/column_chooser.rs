@@ -0,0 +1,108 @@
+use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ColumnChooserProps {
+    /// Labels of the columns by key (see `get_label_map`)
+    pub label_map: HashMap<String, String>,
+    /// Keys of the hidden columns
+    pub hidden_keys: HashSet<String>,
+    /// Keys in listing order; columns left out follow in key order
+    #[props(default)]
+    pub order: Vec<String>,
+    /// Called with the key of the column to show or hide
+    pub on_toggle: EventHandler<String>,
+    /// Called by the reset button, which is rendered only when set
+    #[props(default)]
+    pub on_reset: Option<EventHandler<()>>,
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// Columns listed by a chooser as (key, label), narrowed by a case-insensitive query on labels
+pub fn get_column_chooser_entries(label_map: &HashMap<String, String>, order: &[String], query: &str) -> Vec<(String, String)> {
+    let mut keys: Vec<&String> = label_map.keys().filter(|key| !order.contains(key)).collect();
+    keys.sort();
+    let query = query.trim().to_lowercase();
+
+    order
+        .iter()
+        .filter(|key| label_map.contains_key(*key))
+        .chain(keys)
+        .map(|key| (key.clone(), label_map[key].clone()))
+        .filter(|(_, label)| label.to_lowercase().contains(&query))
+        .collect()
+}
+
+/// カラムの表示・非表示を切り替えるリスト
+#[component]
+pub fn ColumnChooser(props: ColumnChooserProps) -> Element {
+    let mut query = use_signal(String::new);
+
+    let mut class_list = vec!["column-chooser".to_string()];
+
+    if let Some(additional_class) = &props.class {
+        class_list.push(additional_class.clone());
+    }
+
+    let entries = get_column_chooser_entries(&props.label_map, &props.order, &query.read());
+    let shown_count = props.label_map.keys().filter(|key| !props.hidden_keys.contains(*key)).count();
+    let on_toggle = props.on_toggle;
+
+    rsx! {
+        div {
+            class: class_list.join(" "),
+            style: "display: flex; flex-direction: column; gap: 0.25em;",
+
+            div {
+                style: "display: flex; gap: 0.25em;",
+                input {
+                    r#type: "search",
+                    style: "min-width: 0; width: 100%;",
+                    placeholder: "{shown_count} / {props.label_map.len()}",
+                    value: "{query}",
+                    oninput: move |event| query.set(event.value()),
+                }
+                if let Some(on_reset) = props.on_reset {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| on_reset.call(()),
+                        "Reset"
+                    }
+                }
+            }
+            for (key, label) in entries {
+                label {
+                    key: "{key}",
+                    style: "display: block; white-space: nowrap;",
+                    input {
+                        r#type: "checkbox",
+                        checked: !props.hidden_keys.contains(&key),
+                        onchange: {
+                            let key = key.clone();
+                            move |_| on_toggle.call(key.clone())
+                        },
+                    }
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_column_chooser_entries() {
+        let label_map: HashMap<String, String> = [("a", "Name"), ("b", "Age"), ("c", "Nickname")]
+            .into_iter()
+            .map(|(key, label)| (key.to_string(), label.to_string()))
+            .collect();
+        let keys = |entries: Vec<(String, String)>| entries.into_iter().map(|(key, _)| key).collect::<Vec<_>>();
+
+        assert_eq!(keys(get_column_chooser_entries(&label_map, &["c".to_string(), "x".to_string()], "")), vec!["c", "a", "b"]);
+        assert_eq!(keys(get_column_chooser_entries(&label_map, &[], "NAME")), vec!["a", "c"]);
+    }
+}
@@ -1,14 +1,21 @@
 use dioxus::prelude::*;
+use std::ops::Range;
+
+use super::use_table_search::split_by_ranges;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct DataCellProps {
     #[props(default = false)]
     pub hidden: bool,
+    #[props(default = false)]
+    pub focused: bool,
     pub align: String,
     #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
+    #[props(default)]
+    pub onclick: Option<EventHandler<MouseEvent>>,
     pub children: Element,
 }
 
@@ -20,6 +27,10 @@ pub fn DataCell(props: DataCellProps) -> Element {
         class_list.push("hidden".to_string());
     }
     
+    if props.focused {
+        class_list.push("focused".to_string());
+    }
+    
     if let Some(additional_class) = &props.class {
         class_list.push(additional_class.clone());
     }
@@ -31,6 +42,10 @@ pub fn DataCell(props: DataCellProps) -> Element {
         style_str.push_str(" display: none;");
     }
     
+    if props.focused {
+        style_str.push_str(" outline: 2px solid black; outline-offset: -2px;");
+    }
+    
     if let Some(additional_style) = &props.style {
         style_str.push_str(&format!(" {}", additional_style));
     }
@@ -39,7 +54,38 @@ pub fn DataCell(props: DataCellProps) -> Element {
         div {
             class: class_list.join(" "),
             style: style_str,
+            onclick: move |event| {
+                if let Some(onclick) = props.onclick {
+                    onclick.call(event);
+                }
+            },
             {props.children}
         }
     }
 }
+
+#[derive(Props, Clone, PartialEq)]
+pub struct HighlightedTextProps {
+    pub text: String,
+    /// Byte ranges of the text rendered as `<mark>`
+    #[props(default)]
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Cell text with search matches highlighted
+#[component]
+pub fn HighlightedText(props: HighlightedTextProps) -> Element {
+    if props.ranges.is_empty() {
+        return rsx! { "{props.text}" };
+    }
+
+    rsx! {
+        for (segment, highlighted) in split_by_ranges(&props.text, &props.ranges) {
+            if highlighted {
+                mark { "{segment}" }
+            } else {
+                "{segment}"
+            }
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use dioxus::prelude::*;
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use crate::components::table_view::get_column_option_map::{get_cell_text, get_column_option_map, ColumnOptionMap, RenderMap};
+use crate::components::table_view::use_filter::{get_filtered, CustomFilterFn, FilterKind, FilterValue};
+use crate::components::table_view::use_sort::{get_sorted_with_index_fn, Order};
+use crate::components::table_view::use_table_search::find_match;
+
+/// Slice of the data requested from a data source
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DataQuery {
+    /// Sort entries in priority order (same as `UseSortResult::order_entries`)
+    pub sort: Vec<(String, Order)>,
+    /// Column filters ordered by column key
+    pub filters: Vec<(String, FilterValue)>,
+    /// Global quick search query
+    pub search: Option<String>,
+    /// Index of the first row (in the sorted and filtered data)
+    pub offset: usize,
+    /// Maximum number of rows (`None` for every row, when the table isn't paginated)
+    pub limit: Option<usize>,
+}
+
+/// Rows of a query with the total count reported by the source
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Number of rows matching the query's filters, ignoring offset and limit
+    pub total: usize,
+}
+
+pub type FetchFuture<T> = Pin<Box<dyn Future<Output = Page<T>>>>;
+
+/// Server-side data source for `use_table`
+///
+/// Filtering, searching, sorting and paging are delegated to the source.
+pub trait DataSource<T> {
+    fn fetch(&self, query: DataQuery) -> FetchFuture<T>;
+}
+
+/// Data source over an in-memory vector, mainly for tests and prototyping
+///
+/// Applies the column options of the render map the same way `use_table` does locally.
+pub struct InMemoryDataSource<T> {
+    data: Vec<T>,
+    column_option_map: ColumnOptionMap<T>,
+}
+
+impl<T> InMemoryDataSource<T> {
+    pub fn new(data: Vec<T>, render_map: &RenderMap<T>) -> Self {
+        Self {
+            data,
+            column_option_map: get_column_option_map(render_map),
+        }
+    }
+}
+
+impl<T: Clone + 'static> InMemoryDataSource<T> {
+    fn get_cell_text(&self, key: &str, item: &T, index: usize) -> String {
+        self.column_option_map.get(key)
+            .map(|opt| get_cell_text(opt, item, &index.to_string(), index))
+            .unwrap_or_default()
+    }
+
+    /// Rows matching the query before paging
+    pub fn query_all(&self, query: &DataQuery) -> Vec<T> {
+        let custom_filter_map: HashMap<String, CustomFilterFn> = self.column_option_map.iter()
+            .filter_map(|(key, opt)| match &opt.filter {
+                Some(FilterKind::Custom(predicate)) => Some((key.clone(), predicate.clone())),
+                _ => None,
+            })
+            .collect();
+        let filter_map: HashMap<String, FilterValue> = query.filters.iter().cloned().collect();
+        let filtered = get_filtered(self.data.clone(), &filter_map, &custom_filter_map, |key, item, index| {
+            self.get_cell_text(key, item, index)
+        });
+
+        let searched: Vec<T> = match query.search.as_deref().filter(|search| !search.is_empty()) {
+            Some(search) => filtered.into_iter()
+                .enumerate()
+                .filter(|(index, item)| {
+                    self.column_option_map.iter()
+                        .filter(|(_, opt)| !opt.is_hidden)
+                        .any(|(key, _)| find_match(&self.get_cell_text(key, item, *index), search, false).is_some())
+                })
+                .map(|(_, item)| item)
+                .collect(),
+            None => filtered,
+        };
+
+        let asc_sorter_map = self.column_option_map.iter()
+            .map(|(key, opt)| {
+                let sorter = opt.asc_sorter.clone();
+                let typed_sorter = Rc::new(move |a: &T, b: &T| -> Ordering {
+                    (sorter)(a as &dyn Any, b as &dyn Any)
+                }) as Rc<dyn Fn(&T, &T) -> Ordering>;
+                (key.clone(), typed_sorter)
+            })
+            .collect();
+
+        get_sorted_with_index_fn(query.sort.clone(), asc_sorter_map, searched)
+            .into_iter()
+            .map(|it| it.data)
+            .collect()
+    }
+}
+
+impl<T: Clone + 'static> DataSource<T> for InMemoryDataSource<T> {
+    fn fetch(&self, query: DataQuery) -> FetchFuture<T> {
+        let all = self.query_all(&query);
+        let total = all.len();
+        let items = all.into_iter().skip(query.offset).take(query.limit.unwrap_or(usize::MAX)).collect();
+        Box::pin(async move { Page { items, total } })
+    }
+}
+
+#[derive(Clone)]
+pub struct UseDataSourceResult<T: 'static> {
+    /// Latest fetched page (kept while the next one is loading)
+    pub page: Option<Page<T>>,
+    /// Query the page was fetched with
+    pub page_query: Option<DataQuery>,
+    /// Whether a page for the current query is being fetched
+    pub is_loading: bool,
+    /// Fetch the current query again
+    pub refresh: Rc<dyn Fn()>,
+}
+
+/// Hook fetching the page of a query from a data source
+///
+/// Refetches whenever the query changes. The source is fixed on first render,
+/// and `None` disables fetching.
+pub fn use_data_source<T: Clone + 'static>(
+    source: Option<Rc<dyn DataSource<T>>>,
+    query: DataQuery,
+) -> UseDataSourceResult<T> {
+    let is_enabled = source.is_some();
+    let source = use_hook(|| source);
+
+    let resource = use_resource(use_reactive((&query,), move |(query,)| {
+        let source = source.clone();
+        async move {
+            match source {
+                Some(source) => {
+                    let page = source.fetch(query.clone()).await;
+                    Some((query, page))
+                }
+                None => None,
+            }
+        }
+    }));
+
+    let (page_query, page) = match &*resource.read() {
+        Some(Some((page_query, page))) => (Some(page_query.clone()), Some(page.clone())),
+        _ => (None, None),
+    };
+
+    UseDataSourceResult {
+        is_loading: is_enabled && page_query.as_ref() != Some(&query),
+        page,
+        page_query,
+        refresh: Rc::new(move || {
+            let mut resource = resource;
+            resource.restart();
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::table_view::get_column_option_map::{ColumnOptionArgs, RenderMapValue, RenderOptions};
+
+    fn create_source() -> InMemoryDataSource<(String, i32)> {
+        let mut render_map: RenderMap<(String, i32)> = HashMap::new();
+        for (key, get) in [("name", 0), ("age", 1)] {
+            render_map.insert(key.to_string(), RenderMapValue::Options(ColumnOptionArgs {
+                label: key.to_string(),
+                value_mapper: Some(Rc::new(move |raw: &dyn Any, _options: &RenderOptions<(String, i32)>| {
+                    raw.downcast_ref::<(String, i32)>()
+                        .map(|row| if get == 0 { row.0.clone() } else { row.1.to_string() })
+                        .unwrap_or_default()
+                })),
+                asc_sorter: Some(Rc::new(move |a: &dyn Any, b: &dyn Any| {
+                    match (a.downcast_ref::<(String, i32)>(), b.downcast_ref::<(String, i32)>()) {
+                        (Some(a), Some(b)) if get == 0 => a.0.cmp(&b.0),
+                        (Some(a), Some(b)) => a.1.cmp(&b.1),
+                        _ => Ordering::Equal,
+                    }
+                })),
+                ..Default::default()
+            }));
+        }
+        let data = ["Dave", "Alice", "Carol", "Bob", "Eve"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (name.to_string(), 20 + index as i32))
+            .collect();
+        InMemoryDataSource::new(data, &render_map)
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_data_source_page() {
+        let source = create_source();
+
+        let page = source.fetch(DataQuery {
+            sort: vec![("name".to_string(), Order::Asc)],
+            filters: vec![("age".to_string(), FilterValue::NumberRange { min: Some(21.0), max: None })],
+            search: None,
+            offset: 1,
+            limit: Some(2),
+        }).await;
+
+        let names: Vec<&str> = page.items.iter().map(|row| row.0.as_str()).collect();
+        assert_eq!(names, vec!["Bob", "Carol"]);
+        assert_eq!(page.total, 4);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_data_source_search() {
+        let source = create_source();
+
+        let page = source.fetch(DataQuery {
+            sort: vec![("age".to_string(), Order::Desc)],
+            search: Some("E".to_string()),
+            ..DataQuery::default()
+        }).await;
+
+        let names: Vec<&str> = page.items.iter().map(|row| row.0.as_str()).collect();
+        assert_eq!(names, vec!["Eve", "Alice", "Dave"]);
+    }
+}
@@ -0,0 +1,267 @@
+use dioxus::prelude::*;
+use super::use_filter::{parse_number, FilterKind, FilterValue};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FilterCellProps {
+    #[props(default = false)]
+    pub hidden: bool,
+    /// Filter kind of the column (`None` renders an empty cell)
+    pub kind: Option<FilterKind>,
+    /// Current filter of the column
+    pub value: Option<FilterValue>,
+    /// Distinct values of the column for set filters
+    #[props(default)]
+    pub options: Vec<String>,
+    pub set_value: EventHandler<Option<FilterValue>>,
+    #[props(default)]
+    pub class: Option<String>,
+}
+
+/// Text filter from the input (empty query clears the filter)
+pub fn get_text_filter(query: &str, exact: bool) -> Option<FilterValue> {
+    if query.is_empty() {
+        None
+    } else if exact {
+        Some(FilterValue::Equals(query.to_string()))
+    } else {
+        Some(FilterValue::Contains(query.to_string()))
+    }
+}
+
+/// Numeric range filter from the inputs (unparsable bounds are open)
+pub fn get_number_range_filter(min: &str, max: &str) -> Option<FilterValue> {
+    let (min, max) = (parse_number(min), parse_number(max));
+    if min.is_none() && max.is_none() {
+        None
+    } else {
+        Some(FilterValue::NumberRange { min, max })
+    }
+}
+
+/// Date range filter from the inputs (empty bounds are open)
+pub fn get_date_range_filter(from: &str, to: &str) -> Option<FilterValue> {
+    let to_bound = |text: &str| (!text.is_empty()).then(|| text.to_string());
+    let (from, to) = (to_bound(from), to_bound(to));
+    if from.is_none() && to.is_none() {
+        None
+    } else {
+        Some(FilterValue::DateRange { from, to })
+    }
+}
+
+/// Toggle a value of a set filter
+///
+/// No filter means every option is checked, and checking every option clears the filter.
+pub fn toggle_set_filter(value: Option<&FilterValue>, options: &[String], toggled: &str) -> Option<FilterValue> {
+    let mut checked: Vec<String> = match value {
+        Some(FilterValue::OneOf(values)) => values.clone(),
+        _ => options.to_vec(),
+    };
+    if checked.iter().any(|it| it == toggled) {
+        checked.retain(|it| it != toggled);
+    } else {
+        checked.push(toggled.to_string());
+    }
+
+    if options.iter().all(|option| checked.contains(option)) {
+        None
+    } else {
+        Some(FilterValue::OneOf(checked))
+    }
+}
+
+/// ヘッダーのフィルターセル
+#[component]
+pub fn FilterCell(props: FilterCellProps) -> Element {
+    let mut class_list = vec!["filter-cell".to_string()];
+
+    if props.hidden {
+        class_list.push("hidden".to_string());
+    }
+
+    if let Some(additional_class) = &props.class {
+        class_list.push(additional_class.clone());
+    }
+
+    let base_style = "padding: 0.25em 0.5em; border: var(--border-em); border-width: 0 1px 1px 0; overflow: hidden; white-space: nowrap; display: flex; gap: 0.25em;";
+    let mut style_str = base_style.to_string();
+
+    if props.hidden {
+        style_str.push_str(" display: none;");
+    }
+
+    let input_style = "min-width: 0; width: 100%;";
+    let set_value = props.set_value;
+
+    rsx! {
+        div {
+            class: class_list.join(" "),
+            style: style_str,
+            // Keep typing inside the editors from driving table navigation
+            onkeydown: |event| event.stop_propagation(),
+
+            match props.kind.clone() {
+                None => rsx! {},
+                Some(FilterKind::Text) => {
+                    let (query, exact) = match &props.value {
+                        Some(FilterValue::Contains(query)) => (query.clone(), false),
+                        Some(FilterValue::Equals(query)) => (query.clone(), true),
+                        _ => (String::new(), false),
+                    };
+                    let query_for_select = query.clone();
+                    rsx! {
+                        select {
+                            value: if exact { "equals" } else { "contains" },
+                            onchange: move |event| {
+                                set_value.call(get_text_filter(&query_for_select, event.value() == "equals"));
+                            },
+                            option { value: "contains", "∋" }
+                            option { value: "equals", "=" }
+                        }
+                        input {
+                            r#type: "search",
+                            style: input_style,
+                            value: query,
+                            oninput: move |event| set_value.call(get_text_filter(&event.value(), exact)),
+                        }
+                    }
+                }
+                Some(FilterKind::NumberRange) => {
+                    let (min, max) = match &props.value {
+                        Some(FilterValue::NumberRange { min, max }) => (
+                            min.map(|min| min.to_string()).unwrap_or_default(),
+                            max.map(|max| max.to_string()).unwrap_or_default(),
+                        ),
+                        _ => (String::new(), String::new()),
+                    };
+                    let (min_for_max, max_for_min) = (min.clone(), max.clone());
+                    rsx! {
+                        input {
+                            r#type: "number",
+                            style: input_style,
+                            placeholder: "min",
+                            value: min,
+                            oninput: move |event| set_value.call(get_number_range_filter(&event.value(), &max_for_min)),
+                        }
+                        input {
+                            r#type: "number",
+                            style: input_style,
+                            placeholder: "max",
+                            value: max,
+                            oninput: move |event| set_value.call(get_number_range_filter(&min_for_max, &event.value())),
+                        }
+                    }
+                }
+                Some(FilterKind::DateRange) => {
+                    let (from, to) = match &props.value {
+                        Some(FilterValue::DateRange { from, to }) => (
+                            from.clone().unwrap_or_default(),
+                            to.clone().unwrap_or_default(),
+                        ),
+                        _ => (String::new(), String::new()),
+                    };
+                    let (from_for_to, to_for_from) = (from.clone(), to.clone());
+                    rsx! {
+                        input {
+                            r#type: "date",
+                            style: input_style,
+                            value: from,
+                            oninput: move |event| set_value.call(get_date_range_filter(&event.value(), &to_for_from)),
+                        }
+                        input {
+                            r#type: "date",
+                            style: input_style,
+                            value: to,
+                            oninput: move |event| set_value.call(get_date_range_filter(&from_for_to, &event.value())),
+                        }
+                    }
+                }
+                Some(FilterKind::Set) => {
+                    let checked_count = match &props.value {
+                        Some(FilterValue::OneOf(values)) => values.len(),
+                        _ => props.options.len(),
+                    };
+                    let options = props.options.clone();
+                    rsx! {
+                        details {
+                            summary { "{checked_count} / {options.len()}" }
+                            for option in options.iter().cloned() {
+                                label {
+                                    key: "{option}",
+                                    style: "display: block;",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: match &props.value {
+                                            Some(FilterValue::OneOf(values)) => values.contains(&option),
+                                            _ => true,
+                                        },
+                                        onchange: {
+                                            let value = props.value.clone();
+                                            let options = options.clone();
+                                            let option = option.clone();
+                                            move |_| set_value.call(toggle_set_filter(value.as_ref(), &options, &option))
+                                        },
+                                    }
+                                    "{option}"
+                                }
+                            }
+                        }
+                    }
+                }
+                Some(FilterKind::Custom(_)) => {
+                    let input = match &props.value {
+                        Some(FilterValue::Custom(input)) => input.clone(),
+                        _ => String::new(),
+                    };
+                    rsx! {
+                        input {
+                            r#type: "search",
+                            style: input_style,
+                            value: input,
+                            oninput: move |event| {
+                                let input = event.value();
+                                set_value.call((!input.is_empty()).then_some(FilterValue::Custom(input)));
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_text_filter() {
+        assert_eq!(get_text_filter("", true), None);
+        assert_eq!(get_text_filter("a", false), Some(FilterValue::Contains("a".to_string())));
+        assert_eq!(get_text_filter("a", true), Some(FilterValue::Equals("a".to_string())));
+    }
+
+    #[test]
+    fn test_get_range_filters() {
+        assert_eq!(get_number_range_filter("", "x"), None);
+        assert_eq!(
+            get_number_range_filter("1", ""),
+            Some(FilterValue::NumberRange { min: Some(1.0), max: None })
+        );
+        assert_eq!(get_date_range_filter("", ""), None);
+        assert_eq!(
+            get_date_range_filter("", "2024-01-31"),
+            Some(FilterValue::DateRange { from: None, to: Some("2024-01-31".to_string()) })
+        );
+    }
+
+    #[test]
+    fn test_toggle_set_filter() {
+        let options = vec!["a".to_string(), "b".to_string()];
+
+        let unchecked_a = toggle_set_filter(None, &options, "a");
+        assert_eq!(unchecked_a, Some(FilterValue::OneOf(vec!["b".to_string()])));
+
+        assert_eq!(toggle_set_filter(unchecked_a.as_ref(), &options, "a"), None);
+    }
+}
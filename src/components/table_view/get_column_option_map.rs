@@ -5,12 +5,18 @@ use std::rc::Rc;
 
 use crate::function::get_mapped_object::get_mapped_object;
 use crate::components::table_view::use_sort::SortOrder;
+use crate::components::table_view::get_column_bundles_par_row_header::HasRowHeader;
+use crate::components::table_view::use_filter::FilterKind;
+use crate::components::table_view::pinned_columns::Pin;
+use crate::components::table_view::get_column_totals::Aggregate;
+use crate::components::table_view::cell_editor::EditorKind;
 
 // Type aliases for complex function types
 type ValueMapperFn<T> = Option<Rc<dyn Fn(&dyn Any, &RenderOptions<T>) -> String>>;
 type AscSorterFn = Option<Rc<dyn Fn(&dyn Any, &dyn Any) -> Ordering>>;
 type ValueMapperRequired<T> = Rc<dyn Fn(&dyn Any, &RenderOptions<T>) -> String>;
 type AscSorterRequired = Rc<dyn Fn(&dyn Any, &dyn Any) -> Ordering>;
+type NumberMapperFn<T> = Rc<dyn Fn(&T) -> Option<f64>>;
 
 /// Rendering options context passed to value mappers
 #[derive(Debug, Clone)]
@@ -18,6 +24,8 @@ pub struct RenderOptions<T> {
     pub data: T,
     pub id: String,
     pub is_focused: bool,
+    /// Key of the focused column when the row is focused
+    pub focused_column: Option<String>,
     pub is_selected: bool,
     pub data_index: usize,
     pub render_index: usize,
@@ -52,12 +60,58 @@ pub struct ColumnOptionArgs<T> {
     /// For example, `"max-content"` shrinks to maximum content width
     /// Default is `"minmax(max-content, 1fr)"`
     pub init_column_width: Option<String>,
+    /// Minimum width in pixels when resizing
+    /// Default is `20.0`
+    pub min_width_px: Option<f64>,
+    /// Maximum width in pixels when resizing
+    /// Default is `None` (unbounded)
+    pub max_width_px: Option<f64>,
+    /// Side to keep the column on while scrolling horizontally
+    /// Default is `None` (scrolls with the others)
+    pub pinned: Option<Pin>,
     /// Text alignment setting
     /// Default is `"left"`, but specific types [numbers, dates] become `"right"`
     pub align: Option<String>,
     /// Whether to display total values
     /// Default is `false`
     pub total: Option<bool>,
+    /// Aggregate displayed as the total
+    /// Default is `Aggregate::Sum`
+    pub aggregate: Option<Aggregate>,
+    /// Number of the cell for the total
+    /// Default is `None` (the cell text parsed as a number)
+    pub number_mapper: Option<NumberMapperFn<T>>,
+    /// Filter kind editable from the header
+    /// Default is `None` (not filterable)
+    pub filter: Option<FilterKind>,
+    /// Editor of the cells (Enter or F2 starts editing)
+    /// Default is `None` (read-only)
+    pub editor: Option<EditorKind>,
+}
+
+// Not derived, which would require `T: Default`
+impl<T> Default for ColumnOptionArgs<T> {
+    fn default() -> Self {
+        Self {
+            label: String::new(),
+            value_mapper: None,
+            is_row_header: None,
+            asc_sorter: None,
+            init_sort_order: None,
+            sort_order_is_changeable: None,
+            is_hidden: None,
+            init_column_width: None,
+            min_width_px: None,
+            max_width_px: None,
+            pinned: None,
+            align: None,
+            total: None,
+            aggregate: None,
+            number_mapper: None,
+            filter: None,
+            editor: None,
+        }
+    }
 }
 
 /// Complete column option with all required fields
@@ -72,8 +126,34 @@ pub struct ColumnOption<T> {
     pub sort_order_is_changeable: bool,
     pub is_hidden: bool,
     pub init_column_width: String,
+    pub min_width_px: f64,
+    pub max_width_px: Option<f64>,
+    pub pinned: Option<Pin>,
     pub align: String,
     pub total: bool,
+    pub aggregate: Aggregate,
+    pub number_mapper: Option<NumberMapperFn<T>>,
+    pub filter: Option<FilterKind>,
+    pub editor: Option<EditorKind>,
+}
+
+impl<T> ColumnOption<T> {
+    /// Clamp a width to the column's min and max widths
+    pub fn clamp_width_px(&self, width_px: f64) -> f64 {
+        clamp_width_px(width_px, self.min_width_px, self.max_width_px)
+    }
+}
+
+/// Clamp a width to min and max widths (min wins when they conflict)
+pub fn clamp_width_px(width_px: f64, min_width_px: f64, max_width_px: Option<f64>) -> f64 {
+    let width_px = width_px.max(min_width_px);
+    max_width_px.map_or(width_px, |max_width_px| width_px.min(max_width_px.max(min_width_px)))
+}
+
+impl<T> HasRowHeader for ColumnOption<T> {
+    fn is_row_header(&self) -> bool {
+        self.is_row_header
+    }
 }
 
 /// Render map type for column configuration
@@ -99,6 +179,8 @@ pub struct ColumnOption<T> {
 /// ```
 pub type RenderMap<T> = HashMap<String, RenderMapValue<T>>;
 
+// Render maps are built once per render, so options are kept unboxed for literal syntax
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone)]
 pub enum RenderMapValue<T> {
     Label(String),
@@ -130,6 +212,32 @@ pub fn get_column_option_map<T>(render_map: &RenderMap<T>) -> ColumnOptionMap<T>
     )
 }
 
+/// Get column options as a list in display order
+/// Columns are ordered by key so the layout is stable across renders
+pub fn get_column_options<T: Clone>(column_option_map: &ColumnOptionMap<T>) -> Vec<ColumnOption<T>> {
+    let mut options: Vec<ColumnOption<T>> = column_option_map.values().cloned().collect();
+    options.sort_by(|prev, next| prev.key.cmp(&next.key));
+    options
+}
+
+/// Get column options in display order
+///
+/// Columns missing from the order follow in key order, and unknown keys are ignored.
+/// Left pinned columns come first and right pinned ones last.
+pub fn get_ordered_column_options<T: Clone>(column_option_map: &ColumnOptionMap<T>, order: &[String]) -> Vec<ColumnOption<T>> {
+    let mut options: Vec<ColumnOption<T>> = order.iter()
+        .filter_map(|key| column_option_map.get(key).cloned())
+        .collect();
+    options.extend(get_column_options(column_option_map).into_iter().filter(|option| !order.contains(&option.key)));
+    // Stable, so the order is kept within each side
+    options.sort_by_key(|option| match option.pinned {
+        Some(Pin::Left) => 0,
+        None => 1,
+        Some(Pin::Right) => 2,
+    });
+    options
+}
+
 /// Get default alignment based on raw value type
 pub fn get_default_align(raw_value: &dyn Any) -> String {
     // Try to determine type by TypeId (limited but safe approach)
@@ -175,10 +283,17 @@ fn get_column_option<T>(key: &str, render_map_value: &RenderMapValue<T>) -> Colu
             init_column_width: args.init_column_width
                 .clone()
                 .unwrap_or(default_option.init_column_width),
+            min_width_px: args.min_width_px.unwrap_or(default_option.min_width_px),
+            max_width_px: args.max_width_px,
+            pinned: args.pinned,
             align: args.align
                 .clone()
                 .unwrap_or(default_option.align),
             total: args.total.unwrap_or(default_option.total),
+            aggregate: args.aggregate.clone().unwrap_or(default_option.aggregate),
+            number_mapper: args.number_mapper.clone(),
+            filter: args.filter.clone(),
+            editor: args.editor.clone(),
             ..default_option
         },
     }
@@ -201,11 +316,33 @@ pub fn default_column_option<T>(key: &str) -> ColumnOption<T> {
         sort_order_is_changeable: !key.starts_with('_'),
         is_hidden: false,
         init_column_width: "minmax(max-content, 1fr)".to_string(),
+        min_width_px: 20.0,
+        max_width_px: None,
+        pinned: None,
         align: "left".to_string(),
         total: false,
+        aggregate: Aggregate::Sum,
+        number_mapper: None,
+        filter: None,
+        editor: None,
     }
 }
 
+/// Get the text of a cell outside of rendering (e.g. filtering)
+/// Value mappers receive the whole row, with no focus or selection state
+pub fn get_cell_text<T: Clone + 'static>(option: &ColumnOption<T>, row: &T, id: &str, data_index: usize) -> String {
+    (option.value_mapper)(row as &dyn Any, &RenderOptions {
+        data: row.clone(),
+        id: id.to_string(),
+        is_focused: false,
+        focused_column: None,
+        is_selected: false,
+        data_index,
+        render_index: data_index,
+        local_index: data_index,
+    })
+}
+
 pub fn is_column_option_args_object<T>(value: &RenderMapValue<T>) -> bool {
     matches!(value, RenderMapValue::Options(_))
 }
@@ -220,15 +357,7 @@ mod tests {
         render_map.insert("key1".to_string(), RenderMapValue::<()>::Label("Label1".to_string()));
         render_map.insert("key2".to_string(), RenderMapValue::<()>::Options(ColumnOptionArgs {
             label: "Label2".to_string(),
-            value_mapper: None,
-            is_row_header: None,
-            asc_sorter: None,
-            init_sort_order: None,
-            sort_order_is_changeable: None,
-            is_hidden: None,
-            init_column_width: None,
-            align: None,
-            total: None,
+            ..Default::default()
         }));
 
         let label_map = get_label_map(&render_map);
@@ -250,6 +379,32 @@ mod tests {
         assert_eq!(option.key, "key1");
     }
 
+    #[test]
+    fn test_get_column_options_order() {
+        let mut render_map = HashMap::new();
+        render_map.insert("b".to_string(), RenderMapValue::<()>::Label("B".to_string()));
+        render_map.insert("c".to_string(), RenderMapValue::<()>::Label("C".to_string()));
+        render_map.insert("a".to_string(), RenderMapValue::<()>::Label("A".to_string()));
+
+        let options = get_column_options(&get_column_option_map(&render_map));
+        let keys: Vec<&str> = options.iter().map(|option| option.key.as_str()).collect();
+
+        assert_eq!(keys, vec!["a", "b", "c"]);
+
+        let ordered = get_ordered_column_options(&get_column_option_map(&render_map), &["c".to_string(), "x".to_string()]);
+        let ordered_keys: Vec<&str> = ordered.iter().map(|option| option.key.as_str()).collect();
+
+        assert_eq!(ordered_keys, vec!["c", "a", "b"]);
+
+        let mut column_option_map = get_column_option_map(&render_map);
+        column_option_map.get_mut("b").unwrap().pinned = Some(Pin::Left);
+        column_option_map.get_mut("c").unwrap().pinned = Some(Pin::Right);
+        let pinned = get_ordered_column_options(&column_option_map, &["c".to_string()]);
+        let pinned_keys: Vec<&str> = pinned.iter().map(|option| option.key.as_str()).collect();
+
+        assert_eq!(pinned_keys, vec!["b", "a", "c"]);
+    }
+
     #[test]
     fn test_default_column_option() {
         let option = default_column_option::<()>("test_key");
@@ -263,6 +418,22 @@ mod tests {
         assert_eq!(option.init_column_width, "minmax(max-content, 1fr)");
         assert_eq!(option.align, "left");
         assert!(!option.total);
+        assert!(option.filter.is_none());
+        assert!(option.pinned.is_none());
+    }
+
+    #[test]
+    fn test_clamp_width_px() {
+        let option = ColumnOption {
+            min_width_px: 50.0,
+            max_width_px: Some(300.0),
+            ..default_column_option::<()>("key")
+        };
+
+        assert_eq!(option.clamp_width_px(10.0), 50.0);
+        assert_eq!(option.clamp_width_px(120.0), 120.0);
+        assert_eq!(option.clamp_width_px(500.0), 300.0);
+        assert_eq!(default_column_option::<()>("key").clamp_width_px(5000.0), 5000.0);
     }
 
     #[test]
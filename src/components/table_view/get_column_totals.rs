@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::components::table_view::get_column_option_map::{get_cell_text, ColumnOption, ColumnOptionMap};
+use crate::components::table_view::use_filter::parse_number;
+use crate::components::table_view::use_table::DataWithId;
+
+/// Custom reducer over the numbers of a column
+pub type AggregateFn = Rc<dyn Fn(&[f64]) -> Option<f64>>;
+
+/// Aggregate shown in the footer of a column with `total` set
+#[derive(Clone, Default)]
+pub enum Aggregate {
+    #[default]
+    Sum,
+    Average,
+    Min,
+    Max,
+    /// Number of rows
+    Count,
+    Custom(AggregateFn),
+}
+
+impl PartialEq for Aggregate {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Aggregate::Custom(prev), Aggregate::Custom(next)) => Rc::ptr_eq(prev, next),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Aggregate {
+    /// Aggregate the numbers of `row_count` rows (rows without a number are left out)
+    ///
+    /// `None` when there is nothing to aggregate, except for `Count`.
+    pub fn apply(&self, values: &[f64], row_count: usize) -> Option<f64> {
+        match self {
+            Aggregate::Sum => (!values.is_empty()).then(|| values.iter().sum()),
+            Aggregate::Average => (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64),
+            Aggregate::Min => values.iter().copied().reduce(f64::min),
+            Aggregate::Max => values.iter().copied().reduce(f64::max),
+            Aggregate::Count => Some(row_count as f64),
+            Aggregate::Custom(reducer) => reducer(values),
+        }
+    }
+}
+
+/// Rows the totals are computed over
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TotalScope {
+    /// Every row left by the filters and the search
+    #[default]
+    Filtered,
+    /// Rows of the current page only
+    Page,
+}
+
+/// Number of a cell, from the column's `number_mapper` or else its text
+pub fn get_cell_number<T: Clone + Hash + Eq + 'static>(option: &ColumnOption<T>, row: &DataWithId<T>) -> Option<f64> {
+    match &option.number_mapper {
+        Some(number_mapper) => number_mapper(&row.get),
+        None => parse_number(&get_cell_text(option, &row.get, &row.id, row.data_index)),
+    }
+}
+
+/// Totals of the columns with `total` set, by key
+///
+/// Columns whose aggregate has no value are left out.
+pub fn get_column_totals<T: Clone + Hash + Eq + 'static>(column_option_map: &ColumnOptionMap<T>, rows: &[DataWithId<T>]) -> HashMap<String, f64> {
+    column_option_map
+        .iter()
+        .filter(|(_, option)| option.total)
+        .filter_map(|(key, option)| {
+            let values: Vec<f64> = match option.aggregate {
+                // Counting needs no numbers
+                Aggregate::Count => Vec::new(),
+                _ => rows.iter().filter_map(|row| get_cell_number(option, row)).collect(),
+            };
+            option.aggregate.apply(&values, rows.len()).map(|total| (key.clone(), total))
+        })
+        .collect()
+}
+
+/// Total as shown in the footer (at most two decimals)
+pub fn format_total(total: f64) -> String {
+    let text = format!("{total:.2}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::table_view::get_column_option_map::default_column_option;
+
+    fn to_rows(values: &[i32]) -> Vec<DataWithId<i32>> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| DataWithId {
+                get: *value,
+                id: index.to_string(),
+                data_index: index,
+                render_index: Some(index),
+                local_index: None,
+                depth: 0,
+                group_path: Vec::new(),
+                group: None,
+                tree: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_aggregate_apply() {
+        let values = [3.0, 1.0, 2.0];
+
+        assert_eq!(Aggregate::Sum.apply(&values, 4), Some(6.0));
+        assert_eq!(Aggregate::Average.apply(&values, 4), Some(2.0));
+        assert_eq!(Aggregate::Min.apply(&values, 4), Some(1.0));
+        assert_eq!(Aggregate::Max.apply(&values, 4), Some(3.0));
+        assert_eq!(Aggregate::Count.apply(&values, 4), Some(4.0));
+        assert_eq!(Aggregate::Custom(Rc::new(|values: &[f64]| values.first().copied())).apply(&values, 4), Some(3.0));
+        assert_eq!(Aggregate::Average.apply(&[], 0), None);
+    }
+
+    #[test]
+    fn test_get_column_totals() {
+        let mut column_option_map: ColumnOptionMap<i32> = HashMap::new();
+        column_option_map.insert("value".to_string(), ColumnOption {
+            total: true,
+            number_mapper: Some(Rc::new(|value: &i32| Some(*value as f64))),
+            ..default_column_option("value")
+        });
+        column_option_map.insert("max".to_string(), ColumnOption {
+            total: true,
+            aggregate: Aggregate::Max,
+            value_mapper: Rc::new(|raw, _| raw.downcast_ref::<i32>().map(|value| format!("{}", value * 10)).unwrap_or_default()),
+            ..default_column_option("max")
+        });
+        column_option_map.insert("other".to_string(), default_column_option("other"));
+
+        let totals = get_column_totals(&column_option_map, &to_rows(&[1, 5, 3]));
+
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals["value"], 9.0);
+        assert_eq!(totals["max"], 50.0);
+    }
+
+    #[test]
+    fn test_format_total() {
+        assert_eq!(format_total(10.0), "10");
+        assert_eq!(format_total(2.5), "2.5");
+        assert_eq!(format_total(1.0 / 3.0), "0.33");
+    }
+}
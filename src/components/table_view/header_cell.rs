@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
+use super::get_column_option_map::clamp_width_px;
 use super::use_sort::SortOrder;
-use crate::function::obj_calc::{Calc, RhsValue};
 use crate::types::position::{Position, PartialPosition};
 use crate::types::size::{Size, PartialSize};
 
@@ -19,6 +19,21 @@ impl Default for ResizeOrigin {
     }
 }
 
+impl ResizeOrigin {
+    /// Width after the cursor moved to `cursor_x`, clamped to min and max widths
+    pub fn get_resized_width_px(&self, cursor_x: f64, min_width_px: f64, max_width_px: Option<f64>) -> f64 {
+        clamp_width_px(self.element_size.width + cursor_x - self.cursor_position.x, min_width_px, max_width_px)
+    }
+}
+
+/// Column width fitting the content within min and max widths
+pub fn get_fit_content_width(min_width_px: f64, max_width_px: Option<f64>) -> String {
+    match max_width_px {
+        Some(max_width_px) => format!("fit-content({}px)", max_width_px.max(min_width_px)),
+        None => format!("minmax({}px, max-content)", min_width_px),
+    }
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct HeaderCellProps {
     #[props(default = false)]
@@ -27,7 +42,24 @@ pub struct HeaderCellProps {
     pub sort_order: Option<SortOrder>,
     pub shift_sort_order: EventHandler<usize>,
     pub toggle_sort_order_and_apply_once: EventHandler<()>,
+    /// Called with the width while resizing
     pub set_width: EventHandler<Option<String>>,
+    /// Called with the final width when a resize finishes
+    #[props(default)]
+    pub on_resize: Option<EventHandler<Option<String>>>,
+    /// Called with the rendered width of the cell in pixels
+    #[props(default)]
+    pub on_measure: Option<EventHandler<f64>>,
+    #[props(default = 20.0)]
+    pub min_width_px: f64,
+    #[props(default)]
+    pub max_width_px: Option<f64>,
+    /// Called when the cell starts being dragged; the cell is draggable only when set
+    #[props(default)]
+    pub on_drag_start: Option<EventHandler<()>>,
+    /// Called when a dragged cell is dropped on this cell
+    #[props(default)]
+    pub on_drop: Option<EventHandler<()>>,
     pub focused: bool,
     pub is_tail: bool,
     #[props(default)]
@@ -40,10 +72,18 @@ pub struct HeaderCellProps {
 /// ヘッダーセル
 #[component]
 pub fn HeaderCell(props: HeaderCellProps) -> Element {
-    const MIN_WIDTH: f64 = 20.0;
     const SHOW_RESIZE_THUMB: bool = true;
     
-    let mut resize_origin = use_signal(ResizeOrigin::default);
+    // Set while dragging the resize thumb
+    let mut resize_origin = use_signal(|| None::<ResizeOrigin>);
+    // Width set by the current drag, committed when it ends
+    let mut resized_width = use_signal(|| None::<String>);
+    // Swallows the click that ends a drag so that it does not sort
+    let mut is_click_after_resize = use_signal(|| false);
+    // Rendered width of the cell
+    let mut measured_width_px = use_signal(|| None::<f64>);
+    // Set while a dragged cell is over this cell
+    let mut is_drag_over = use_signal(|| false);
     
     let data_sort_order = if props.sort_order_is_changeable {
         props.sort_order.as_ref().map(|o| o.to_str())
@@ -61,6 +101,10 @@ pub fn HeaderCell(props: HeaderCellProps) -> Element {
         class_list.push("focused".to_string());
     }
     
+    if is_drag_over() {
+        class_list.push("drag-over".to_string());
+    }
+    
     if let Some(additional_class) = &props.class {
         class_list.push(additional_class.clone());
     }
@@ -81,6 +125,10 @@ pub fn HeaderCell(props: HeaderCellProps) -> Element {
         style_str.push_str(" outline: 2px solid black;");
     }
     
+    if is_drag_over() {
+        style_str.push_str(" box-shadow: inset 2px 0 0 var(--color-em);");
+    }
+    
     if props.hidden {
         style_str.push_str(" display: none;");
     }
@@ -89,16 +137,84 @@ pub fn HeaderCell(props: HeaderCellProps) -> Element {
     let sort_before_after_base = "content: ''; position: absolute; top: 50%; right: 3px; width: 0; height: 0; border: 5px solid transparent;";
     
     let resize_thumb_style = if SHOW_RESIZE_THUMB {
-        "cursor: col-resize; position: absolute; top: 0; right: 0; bottom: 0; width: 3px; background-color: var(--color-em);"
+        "cursor: col-resize; position: absolute; top: 0; right: 0; bottom: 0; width: 3px; background-color: var(--color-em); touch-action: none;"
     } else {
         "display: none;"
     };
+    
+    let (min_width_px, max_width_px) = (props.min_width_px, props.max_width_px);
+    let set_width = props.set_width;
+    let on_resize = props.on_resize;
+    let on_measure = props.on_measure;
+    let (on_drag_start, on_drop) = (props.on_drag_start, props.on_drop);
+    
+    let mut set_measured_width = move |width_px: f64| {
+        if *measured_width_px.peek() != Some(width_px) {
+            measured_width_px.set(Some(width_px));
+            if let Some(on_measure) = on_measure {
+                on_measure.call(width_px);
+            }
+        }
+    };
+    
+    let mut finish_resize = move || {
+        if resize_origin.take().is_none() {
+            return;
+        }
+        is_click_after_resize.set(true);
+        if let (Some(on_resize), Some(width)) = (on_resize, resized_width.take()) {
+            on_resize.call(Some(width));
+        }
+    };
 
     rsx! {
         div {
             class: class_list.join(" "),
             style: style_str,
+            draggable: on_drag_start.is_some(),
+            ondragstart: move |event: DragEvent| {
+                // The resize thumb drags the width, not the column
+                if resize_origin.peek().is_some() {
+                    event.prevent_default();
+                    return;
+                }
+                if let Some(on_drag_start) = on_drag_start {
+                    on_drag_start.call(());
+                }
+            },
+            ondragover: move |event: DragEvent| {
+                if on_drop.is_none() {
+                    return;
+                }
+                // Allows dropping on this cell
+                event.prevent_default();
+                if !*is_drag_over.peek() {
+                    is_drag_over.set(true);
+                }
+            },
+            ondragleave: move |_| is_drag_over.set(false),
+            ondrop: move |event: DragEvent| {
+                event.prevent_default();
+                is_drag_over.set(false);
+                if let Some(on_drop) = on_drop {
+                    on_drop.call(());
+                }
+            },
+            onmounted: move |event: MountedEvent| async move {
+                if let Ok(rect) = event.data().get_client_rect().await {
+                    set_measured_width(rect.width());
+                }
+            },
+            onresize: move |event: ResizeEvent| {
+                if let Ok(size) = event.get_border_box_size() {
+                    set_measured_width(size.width);
+                }
+            },
             onclick: move |event| {
+                if is_click_after_resize.replace(false) {
+                    return;
+                }
+                
                 if let Some(onclick) = props.onclick {
                     onclick.call(event);
                 }
@@ -140,63 +256,76 @@ pub fn HeaderCell(props: HeaderCellProps) -> Element {
                 class: resize_thumb_classes.join(" "),
                 style: resize_thumb_style,
                 onclick: |event| event.stop_propagation(),
-                onmousedown: move |event| {
-                    let cursor_pos = Position::from_partial(PartialPosition {
+                onpointerdown: move |event: PointerEvent| {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    let cursor_position = Position::from_partial(PartialPosition {
                         x: Some(event.client_coordinates().x),
                         y: Some(event.client_coordinates().y),
                     });
-                    
-                    // Note: In a real implementation, you'd need to get the parent element size
-                    // This is a simplified version
                     let element_size = Size::from_partial(PartialSize {
-                        width: Some(100.0), // Would be actual element width
-                        height: Some(30.0), // Would be actual element height
+                        width: Some(measured_width_px.peek().unwrap_or(min_width_px)),
+                        height: None,
                     });
-                    
-                    resize_origin.set(ResizeOrigin {
-                        cursor_position: cursor_pos,
+                    resized_width.set(None);
+                    resize_origin.set(Some(ResizeOrigin {
+                        cursor_position,
                         element_size,
-                    });
+                    }));
                 },
-                onmousemove: move |event| {
-                    let current_cursor = Position::from_partial(PartialPosition {
-                        x: Some(event.client_coordinates().x),
-                        y: Some(event.client_coordinates().y),
-                    });
-                    
-                    let origin = resize_origin.read();
-                    let cursor_pos_map = std::collections::HashMap::from([
-                        ("x".to_string(), current_cursor.x),
-                        ("y".to_string(), current_cursor.y),
-                    ]);
-                    let origin_cursor_map = std::collections::HashMap::from([
-                        ("x".to_string(), origin.cursor_position.x),
-                        ("y".to_string(), origin.cursor_position.y),
-                    ]);
-                    
-                    let vector = Calc::minus(cursor_pos_map, RhsValue::Object(origin_cursor_map));
-                    let vector_position = Position {
-                        x: vector.get("x").copied().unwrap_or(0.0),
-                        y: vector.get("y").copied().unwrap_or(0.0),
-                    };
-                    
-                    let origin_size_map = std::collections::HashMap::from([
-                        ("width".to_string(), origin.element_size.width),
-                        ("height".to_string(), origin.element_size.height),
-                    ]);
-                    let vector_size = Size::from_position(vector_position);
-                    let vector_size_map = std::collections::HashMap::from([
-                        ("width".to_string(), vector_size.width),
-                        ("height".to_string(), vector_size.height),
-                    ]);
-                    
-                    let resized = Calc::plus(origin_size_map, RhsValue::Object(vector_size_map));
-                    let new_width = resized.get("width").copied().unwrap_or(MIN_WIDTH);
-                    
-                    let final_width = new_width.max(MIN_WIDTH);
-                    props.set_width.call(Some(format!("{}px", final_width)));
+                ondoubleclick: move |event| {
+                    event.stop_propagation();
+                    let width = get_fit_content_width(min_width_px, max_width_px);
+                    set_width.call(Some(width.clone()));
+                    if let Some(on_resize) = on_resize {
+                        on_resize.call(Some(width));
+                    }
+                },
+            }
+            
+            // Captures the pointer while dragging, so that the drag continues outside of the thumb
+            if resize_origin.read().is_some() {
+                div {
+                    class: "resize-capture",
+                    style: "position: fixed; inset: 0; z-index: 1000; cursor: col-resize; touch-action: none;",
+                    onpointermove: move |event: PointerEvent| {
+                        let Some(origin) = resize_origin.peek().clone() else {
+                            return;
+                        };
+                        let width_px = origin.get_resized_width_px(event.client_coordinates().x, min_width_px, max_width_px);
+                        let width = format!("{}px", width_px);
+                        if resized_width.peek().as_ref() != Some(&width) {
+                            resized_width.set(Some(width.clone()));
+                            set_width.call(Some(width));
+                        }
+                    },
+                    onpointerup: move |_| finish_resize(),
+                    onpointercancel: move |_| finish_resize(),
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_resized_width_px() {
+        let origin = ResizeOrigin {
+            cursor_position: Position { x: 100.0, y: 0.0 },
+            element_size: Size { width: 120.0, height: 30.0 },
+        };
+
+        assert_eq!(origin.get_resized_width_px(150.0, 20.0, None), 170.0);
+        assert_eq!(origin.get_resized_width_px(0.0, 40.0, None), 40.0);
+        assert_eq!(origin.get_resized_width_px(400.0, 20.0, Some(200.0)), 200.0);
+    }
+
+    #[test]
+    fn test_get_fit_content_width() {
+        assert_eq!(get_fit_content_width(20.0, None), "minmax(20px, max-content)");
+        assert_eq!(get_fit_content_width(20.0, Some(300.0)), "fit-content(300px)");
+    }
+}
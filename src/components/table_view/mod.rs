@@ -1,16 +1,32 @@
 pub mod get_column_option_map;
+pub mod table_row;
 pub mod rsx;
 pub mod signal;
 pub mod use_table;
+pub mod data_source;
+pub mod use_table_search;
 pub mod use_sort;
+pub mod use_filter;
 pub mod use_select;
 pub mod use_column_widthes;
+pub mod use_column_order;
+pub mod use_column_visibility;
+pub mod pinned_columns;
+pub mod get_column_totals;
+pub mod use_row_grouping;
+pub mod use_row_expansion;
+pub mod use_cell_edit;
+pub mod use_tree_data;
+pub mod use_column_virtualization;
 pub mod use_focus;
 pub mod use_focus_fn;
 pub mod use_pagination;
 pub mod use_pagination_fn;
 pub mod column_bundle;
 pub mod column_bundles;
+pub mod column_chooser;
+pub mod cell_editor;
 pub mod data_cell;
+pub mod filter_cell;
 pub mod get_column_bundles_par_row_header;
 pub mod header_cell;
\ No newline at end of file
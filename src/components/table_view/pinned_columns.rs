@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+/// Side a column is pinned to while scrolling horizontally
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pin {
+    Left,
+    Right,
+}
+
+/// Sticky offset of a pinned column
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PinnedOffset {
+    pub pin: Pin,
+    /// Distance from the pinned side in pixels
+    pub offset_px: f64,
+}
+
+impl PinnedOffset {
+    pub fn get_style(&self) -> String {
+        let side = match self.pin {
+            Pin::Left => "left",
+            Pin::Right => "right",
+        };
+        format!("position: sticky; {side}: {}px; z-index: 1; background: var(--background-color, Canvas);", self.offset_px)
+    }
+}
+
+/// Sticky offsets of the pinned columns
+///
+/// `columns` are the visible columns in grid order as (key, pin, width in pixels).
+/// Left pinned columns are offset by the widths of the left pinned columns before them,
+/// and right pinned columns by the widths of the right pinned columns after them.
+pub fn get_pinned_offsets(columns: &[(String, Option<Pin>, f64)]) -> HashMap<String, PinnedOffset> {
+    let mut offsets = HashMap::new();
+
+    let mut left_px = 0.0;
+    for (key, pin, width) in columns {
+        if *pin == Some(Pin::Left) {
+            offsets.insert(key.clone(), PinnedOffset { pin: Pin::Left, offset_px: left_px });
+            left_px += width;
+        }
+    }
+
+    let mut right_px = 0.0;
+    for (key, pin, width) in columns.iter().rev() {
+        if *pin == Some(Pin::Right) {
+            offsets.insert(key.clone(), PinnedOffset { pin: Pin::Right, offset_px: right_px });
+            right_px += width;
+        }
+    }
+
+    offsets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_pinned_offsets() {
+        let columns: Vec<(String, Option<Pin>, f64)> = [
+            ("id", Some(Pin::Left), 80.0),
+            ("code", Some(Pin::Left), 60.0),
+            ("name", None, 200.0),
+            ("total", Some(Pin::Right), 100.0),
+            ("status", Some(Pin::Right), 50.0),
+        ]
+            .into_iter()
+            .map(|(key, pin, width)| (key.to_string(), pin, width))
+            .collect();
+
+        let offsets = get_pinned_offsets(&columns);
+
+        assert_eq!(offsets.len(), 4);
+        assert_eq!(offsets["id"], PinnedOffset { pin: Pin::Left, offset_px: 0.0 });
+        assert_eq!(offsets["code"], PinnedOffset { pin: Pin::Left, offset_px: 80.0 });
+        assert_eq!(offsets["total"], PinnedOffset { pin: Pin::Right, offset_px: 50.0 });
+        assert_eq!(offsets["status"], PinnedOffset { pin: Pin::Right, offset_px: 0.0 });
+        assert_eq!(offsets["code"].get_style(), "position: sticky; left: 80px; z-index: 1; background: var(--background-color, Canvas);");
+    }
+}
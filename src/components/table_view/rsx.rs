@@ -1,137 +1,1189 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
 use dioxus::prelude::*;
 
-/// Simple TableView component converted from React
-/// This is a basic implementation with core table functionality
+use super::cell_editor::CellEditor;
+use super::column_bundle::{ColumnBundle, ColumnBundleStyles, ColumnSpacer};
+use super::data_cell::{DataCell, HighlightedText};
+use super::filter_cell::FilterCell;
+use super::get_column_bundles_par_row_header::get_column_bundles_par_row_header;
+use super::get_column_totals::format_total;
+use super::get_column_option_map::{get_cell_text, get_ordered_column_options, ColumnOption, RenderOptions};
+use super::header_cell::HeaderCell;
+use super::pinned_columns::{get_pinned_offsets, Pin, PinnedOffset};
+use super::use_cell_edit::{use_cell_edit, CellEdit, OnCellEditFn};
+use super::use_column_virtualization::{parse_px_width, UseColumnVirtualizationResult};
+use super::use_filter::{FilterKind, FilterValue};
+use super::use_focus::get_shifted_column_key;
+use super::use_focus_fn::FocusByIdOptions;
+use super::use_sort::Order;
+use super::use_table::DataWithId;
+use super::use_tree_data::TreeNode;
+use crate::function::input_event_util::{is_arrow_left_actionable, is_arrow_right_actionable};
+use crate::types::setter::SetStateAction;
+
+/// TableView component rendering the state built by `use_table`
+///
+/// Headers come from the column option map, cells are rendered with each
+/// column's `value_mapper`, and consecutive row header columns are grouped
+/// into `ColumnBundle`s.
 
 #[derive(Props, Clone, PartialEq)]
-pub struct TableViewProps {
-    /// Table data as strings for simplicity
-    pub data: Vec<Vec<String>>,
-    
-    /// Column headers
-    #[props(default = vec![])]
-    pub headers: Vec<String>,
-    
+pub struct TableViewProps<T: Clone + Hash + Eq + 'static> {
+    /// Table state built by `use_table`
+    pub state_props: TableViewStateProps<T>,
+
     /// Table title
     #[props(default)]
     pub title: Option<String>,
-    
+
     /// Additional CSS class
     #[props(default)]
     pub class: Option<String>,
-    
+
     /// Additional inline style
     #[props(default)]
     pub style: Option<String>,
-    
+
     /// Tab index for focus management
     #[props(default)]
     pub tab_index: Option<i32>,
+
+    /// Called with the focused row when Enter is pressed
+    #[props(default)]
+    pub on_row_activate: Option<EventHandler<DataWithId<T>>>,
+
+    /// Called when a column resize finishes (to persist widths)
+    #[props(default)]
+    pub on_column_resize: Option<EventHandler<ColumnResizeEvent>>,
+
+    /// Renders the full-width detail panel below an expanded row
+    ///
+    /// Rows get a toggle in their first cell when set.
+    #[props(default)]
+    pub render_detail: Option<Callback<DataWithId<T>, Element>>,
+
+    /// Called with the value of an edited cell on commit
+    ///
+    /// An error keeps the editor open and is shown under it.
+    #[props(default)]
+    pub on_cell_edit: Option<OnCellEditFn>,
 }
 
+/// Column width set by dragging, double-clicking or Alt+Arrow on a header
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnResizeEvent {
+    pub key: String,
+    pub width: Option<String>,
+}
+
+/// Width step of Alt+ArrowLeft / Alt+ArrowRight in pixels
+const KEYBOARD_RESIZE_STEP_PX: f64 = 10.0;
+
+/// Column bundle with its position on the css grid
+#[derive(Clone)]
+pub struct PlacedColumnBundle<T> {
+    /// Row header columns of the bundle
+    pub headers: Vec<ColumnOption<T>>,
+    /// Content columns of the bundle
+    pub contents: Vec<ColumnOption<T>>,
+    /// Grid column where the bundle starts (0-based, visible columns only)
+    pub column_start: usize,
+    /// Index of the bundle's first visible unpinned content column among all of them
+    pub content_start: usize,
+}
+
+/// Row header or content part of a bundle as rendered
+pub struct BundlePart<'a, T> {
+    pub is_row_header: bool,
+    pub column_start: usize,
+    /// Visible columns of the part, including the ones skipped by the column virtualization
+    pub column_count: usize,
+    /// Columns left out before the rendered ones
+    pub skipped_before: usize,
+    pub columns: Vec<&'a ColumnOption<T>>,
+    /// Columns left out after the rendered ones
+    pub skipped_after: usize,
+    /// Sticky offset when the columns are pinned
+    pub pinned: Option<PinnedOffset>,
+}
+
+impl<T> BundlePart<'_, T> {
+    pub fn get_pinned_style(&self) -> String {
+        self.pinned.map(|pinned| pinned.get_style()).unwrap_or_default()
+    }
+}
+
+impl<T> PlacedColumnBundle<T> {
+    pub fn header_count(&self) -> usize {
+        get_visible_count(&self.headers)
+    }
+
+    pub fn content_count(&self) -> usize {
+        get_visible_count(&self.contents)
+    }
+
+    /// Row header and content parts, split where the pin changes
+    ///
+    /// Each pinned run becomes its own part so that it can stick as a whole.
+    /// Only the unpinned content columns in the window are rendered.
+    pub fn parts(
+        &self,
+        column_virtualization: &UseColumnVirtualizationResult,
+        pinned_offsets: &HashMap<String, PinnedOffset>,
+    ) -> Vec<BundlePart<'_, T>> {
+        let get_pin = |column: &ColumnOption<T>| column_virtualization.get_pin(column);
+        let mut parts = Vec::new();
+        let mut column_start = self.column_start;
+        let mut content_start = self.content_start;
+
+        for (is_row_header, columns) in [(true, &self.headers), (false, &self.contents)] {
+            for run in columns.chunk_by(|prev, next| get_pin(prev) == get_pin(next)) {
+                let pin = get_pin(&run[0]);
+                let column_count = get_visible_count(run);
+                let (skipped_before, rendered, skipped_after) = if is_row_header || pin.is_some() {
+                    (0, run.iter().collect(), 0)
+                } else {
+                    let split = column_virtualization.split(run, content_start, |column| column.is_hidden);
+                    content_start += column_count;
+                    split
+                };
+                // The offset of the run is the one of its column nearest to the pinned side
+                let mut visible = run.iter().filter(|column| !column.is_hidden);
+                let pinned_column = match pin {
+                    Some(Pin::Left) => visible.next(),
+                    Some(Pin::Right) => visible.next_back(),
+                    None => None,
+                };
+
+                parts.push(BundlePart {
+                    is_row_header,
+                    column_start,
+                    column_count,
+                    skipped_before,
+                    columns: rendered,
+                    skipped_after,
+                    pinned: pinned_column.and_then(|column| pinned_offsets.get(&column.key)).copied(),
+                });
+                column_start += column_count;
+            }
+        }
+        parts
+    }
+}
+
+/// Bundle part as laid out in every row, with the keys of its rendered columns
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowPart {
+    pub is_row_header: bool,
+    pub column_start: usize,
+    pub column_count: usize,
+    pub skipped_before: usize,
+    pub column_keys: Vec<String>,
+    pub skipped_after: usize,
+    pub style: String,
+}
+
+impl<T> From<BundlePart<'_, T>> for RowPart {
+    fn from(part: BundlePart<'_, T>) -> Self {
+        Self {
+            is_row_header: part.is_row_header,
+            column_start: part.column_start,
+            column_count: part.column_count,
+            skipped_before: part.skipped_before,
+            column_keys: part.columns.iter().map(|column| column.key.clone()).collect(),
+            skipped_after: part.skipped_after,
+            style: part.get_pinned_style(),
+        }
+    }
+}
+
+fn get_visible_count<T>(columns: &[ColumnOption<T>]) -> usize {
+    columns.iter().filter(|column| !column.is_hidden).count()
+}
+
+/// Group columns per consecutive row header and place them on the grid
+///
+/// Hidden columns stay in their bundle but take no grid column.
+pub fn get_placed_column_bundles<T: Clone>(columns: Vec<ColumnOption<T>>) -> Vec<PlacedColumnBundle<T>> {
+    get_column_bundles_par_row_header(columns)
+        .into_iter()
+        .scan((0, 0), |(column_start, content_start), bundle| {
+            let placed = PlacedColumnBundle {
+                headers: bundle.headers,
+                contents: bundle.contents,
+                column_start: *column_start,
+                content_start: *content_start,
+            };
+            *column_start += placed.header_count() + placed.content_count();
+            *content_start += placed.contents.iter()
+                .filter(|column| !column.is_hidden && column.pinned.is_none())
+                .count();
+            Some(placed)
+        })
+        .collect()
+}
+
+/// Keyboard event and focused row state that decide the action of a key
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyInput {
+    pub key: Key,
+    /// Ctrl or Meta
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    /// ArrowLeft isn't moving the cursor of an input
+    pub arrow_left_actionable: bool,
+    /// ArrowRight isn't moving the cursor of an input
+    pub arrow_right_actionable: bool,
+    pub select_many: bool,
+    pub has_focused_column: bool,
+    /// The focused cell has an editor
+    pub has_focused_edit: bool,
+    pub is_group_row: bool,
+    /// Tree state of the focused row, when focused on the toggle column
+    pub tree: Option<TreeNode>,
+}
+
+/// Action of a key on the table
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeyAction {
+    /// Move the focus by rows and extend the selection to it
+    ExtendSelection(isize),
+    MoveRow(isize),
+    /// Resize the focused column by pixels
+    ResizeColumn(f64),
+    SetTreeExpanded(bool),
+    FocusParent,
+    MoveColumn(isize),
+    PreviousPage,
+    NextPage,
+    FirstRow,
+    LastRow,
+    FirstRowOfPage,
+    LastRowOfPage,
+    StartEdit,
+    ToggleGroup,
+    /// Call `on_row_activate` with the focused row
+    ActivateRow,
+    ToggleSelect,
+    SelectAll,
+}
+
+/// Map a key to its action on the table (`None` leaves the key to the browser)
+pub fn get_key_action(input: &KeyInput) -> Option<KeyAction> {
+    let tree = input.tree.as_ref();
+    let action = match &input.key {
+        // Shift+ArrowUp / Shift+ArrowDown extend the selection from the anchor
+        Key::ArrowUp if input.shift => KeyAction::ExtendSelection(-1),
+        Key::ArrowDown if input.shift => KeyAction::ExtendSelection(1),
+        Key::ArrowUp => KeyAction::MoveRow(-1),
+        Key::ArrowDown => KeyAction::MoveRow(1),
+        // Alt+ArrowLeft / Alt+ArrowRight resize the focused column
+        Key::ArrowLeft | Key::ArrowRight if input.alt => {
+            if !input.has_focused_column {
+                return None;
+            }
+            let step = if input.key == Key::ArrowLeft { -KEYBOARD_RESIZE_STEP_PX } else { KEYBOARD_RESIZE_STEP_PX };
+            KeyAction::ResizeColumn(step)
+        }
+        Key::ArrowRight if tree.is_some_and(|tree| tree.has_children && !tree.is_expanded) => KeyAction::SetTreeExpanded(true),
+        Key::ArrowLeft if tree.is_some_and(|tree| tree.is_expanded) => KeyAction::SetTreeExpanded(false),
+        // ArrowLeft on a collapsed child moves to its parent
+        Key::ArrowLeft if tree.is_some_and(|tree| tree.parent_id.is_some()) => KeyAction::FocusParent,
+        Key::ArrowLeft if input.arrow_left_actionable => KeyAction::MoveColumn(-1),
+        Key::ArrowRight if input.arrow_right_actionable => KeyAction::MoveColumn(1),
+        Key::PageUp => KeyAction::PreviousPage,
+        Key::PageDown => KeyAction::NextPage,
+        // Ctrl+Home / Ctrl+End move across the whole table, Home / End within the page
+        Key::Home if input.ctrl => KeyAction::FirstRow,
+        Key::End if input.ctrl => KeyAction::LastRow,
+        Key::Home => KeyAction::FirstRowOfPage,
+        Key::End => KeyAction::LastRowOfPage,
+        Key::Enter | Key::F2 if input.has_focused_edit => KeyAction::StartEdit,
+        // Enter / Space expand or collapse a focused group row
+        Key::Enter if input.is_group_row => KeyAction::ToggleGroup,
+        Key::Character(ch) if ch == " " && input.is_group_row => KeyAction::ToggleGroup,
+        Key::Enter => KeyAction::ActivateRow,
+        Key::Character(ch) if ch == " " => KeyAction::ToggleSelect,
+        // Ctrl+A selects every row of the current view
+        Key::Character(ch) if input.ctrl && input.select_many && ch.eq_ignore_ascii_case("a") => KeyAction::SelectAll,
+        _ => return None,
+    };
+    Some(action)
+}
+
+/// Focus options that leave the selection untouched
+fn without_select() -> Option<FocusByIdOptions> {
+    Some(FocusByIdOptions {
+        without_scroll: None,
+        with_select: Some(SetStateAction::Value(false)),
+    })
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct GridRowProps {
+    column_count: usize,
+    /// Bundle parts of the row, with the columns to render
+    parts: Rc<Vec<RowPart>>,
+    /// Renders the cell of a column key
+    render_cell: Callback<String, Element>,
+    #[props(default)]
+    class: String,
+    #[props(default)]
+    style: String,
+    /// Mount handler (to measure the row)
+    #[props(default)]
+    onmounted: Option<EventHandler<MountedEvent>>,
+    /// Resize handler (to measure the row)
+    #[props(default)]
+    onresize: Option<EventHandler<ResizeEvent>>,
+    /// Expanded state of group and tree rows
+    #[props(default)]
+    aria_expanded: Option<bool>,
+    /// Content after the cells (e.g. a detail panel)
+    #[props(default)]
+    children: Element,
+}
+
+/// Row of the grid with a `ColumnBundle` per bundle part and a cell per rendered column
 #[component]
-pub fn TableView(props: TableViewProps) -> Element {
+pub fn GridRow(props: GridRowProps) -> Element {
+    rsx! {
+        ColumnBundle {
+            column_count: props.column_count as i32,
+            class: props.class,
+            style: props.style,
+            onmounted: props.onmounted,
+            onresize: props.onresize,
+            "aria-expanded": props.aria_expanded,
+            for part in props.parts.iter() {
+                ColumnBundle {
+                    key: "{part.column_start}-{part.is_row_header}",
+                    column_start: part.column_start as i32,
+                    column_count: part.column_count as i32,
+                    is_row_header: part.is_row_header,
+                    style: part.style.clone(),
+                    if part.skipped_before > 0 {
+                        ColumnSpacer { span: part.skipped_before }
+                    }
+                    for key in part.column_keys.iter() {
+                        Fragment {
+                            key: "{key}",
+                            {props.render_cell.call(key.clone())}
+                        }
+                    }
+                    if part.skipped_after > 0 {
+                        ColumnSpacer { span: part.skipped_after }
+                    }
+                }
+            }
+            {props.children}
+        }
+    }
+}
+
+#[component]
+pub fn TableView<T: Clone + Hash + Eq + 'static>(props: TableViewProps<T>) -> Element {
+    // Shared with the cell renderers of the rows
+    let state = Rc::new(props.state_props.clone());
+
     // Build CSS class
     let table_class = match &props.class {
         Some(additional_class) => format!("Table {}", additional_class),
         None => "Table".to_string(),
     };
-    
+
     // Build CSS style
     let table_style = props.style.clone().unwrap_or_default();
-    
+    let render_detail = props.render_detail;
+
+    // Column layout
+    let placed_bundles = get_placed_column_bundles(get_ordered_column_options(&state.column_option_map, &state.column_order.order));
+    let visible_columns: Vec<&ColumnOption<T>> = placed_bundles
+        .iter()
+        .flat_map(|bundle| bundle.headers.iter().chain(bundle.contents.iter()))
+        .filter(|column| !column.is_hidden)
+        .collect();
+    let grid_template_columns = visible_columns
+        .iter()
+        .map(|column| {
+            // The column virtualization lays out columns with the widths it assumes
+            if let Some(width_px) = state.column_virtualization.width_px_map.get(&column.key) {
+                return format!("{width_px}px");
+            }
+            state.column_widthes.get
+                .get(&column.key)
+                .cloned()
+                .flatten()
+                .unwrap_or_else(|| column.init_column_width.clone())
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let head_key = visible_columns.first().map(|column| column.key.clone());
+    // Tree data shows its disclosure toggles in the first row header column
+    let tree_key = visible_columns.iter()
+        .find(|column| column.is_row_header)
+        .or(visible_columns.first())
+        .map(|column| column.key.clone());
+    let tail_key = visible_columns.last().map(|column| column.key.clone());
+    let column_keys: Vec<String> = visible_columns.iter().map(|column| column.key.clone()).collect();
+    let column_count = visible_columns.len();
+    let has_filter = visible_columns.iter().any(|column| column.filter.is_some());
+    let has_total = visible_columns.iter().any(|column| column.total);
+
+    // Virtual scroll spacers
+    let before_height_px = state.virtual_scroll.props.before_height_px;
+    let rendered_until = state.virtualized_data
+        .last()
+        .and_then(|row| row.local_index)
+        .map(|local_index| local_index + 1)
+        .unwrap_or(0);
+    let after_height_px = (state.virtual_scroll.props.max_height_px
+        - (state.virtual_scroll.get_offset_px_by_index)(rendered_until))
+        .max(0.0);
+
+    // Pagination
+    let page = state.pagination.state.page;
+    let max_page = (state.pagination.state.max_page)(state.data_length.saturating_sub(1));
+
+    let mut scroll_element = use_signal(|| None::<Rc<MountedData>>);
+    // Rendered header widths for keyboard resizing of columns without a pixel width
+    let mut header_widths = use_signal(HashMap::<String, f64>::new);
+    // Key of the header being dragged to reorder the columns
+    let mut dragged_column = use_signal(|| None::<String>);
+    // Height of the sticky header row, under which the filter row sticks
+    let mut header_row_height_px = use_signal(|| 0.0_f64);
+    // The table takes the keyboard focus back from closed cell editors
+    let mut table_element = use_signal(|| None::<Rc<MountedData>>);
+    let focus_table = move || {
+        if let Some(element) = table_element.peek().clone() {
+            spawn(async move {
+                let _ = element.set_focus(true).await;
+            });
+        }
+    };
+    let cell_edit = use_cell_edit(props.on_cell_edit);
+
+    // Sticky offsets of the pinned columns, from pixel widths or else the rendered ones
+    let pinned_offsets = get_pinned_offsets(&visible_columns
+        .iter()
+        .map(|column| {
+            let width_px = state.column_virtualization.width_px_map.get(&column.key).copied()
+                .or_else(|| {
+                    state.column_widthes.get.get(&column.key)
+                        .cloned()
+                        .flatten()
+                        .and_then(|width| parse_px_width(&width))
+                })
+                .or_else(|| parse_px_width(&column.init_column_width))
+                .or_else(|| header_widths.read().get(&column.key).copied())
+                .unwrap_or(column.min_width_px);
+            (column.key.clone(), state.column_virtualization.get_pin(column), width_px)
+        })
+        .collect::<Vec<_>>());
+
     // Keyboard event handler
-    let handle_key_down = move |event: KeyboardEvent| {
-        match event.key() {
-            Key::ArrowDown | Key::ArrowUp | Key::ArrowLeft | Key::ArrowRight => {
-                event.prevent_default();
-                // Basic keyboard navigation would go here
+    let handle_key_down = {
+        let focus = state.focus.clone();
+        let select = state.select.clone();
+        let extend_select_to = state.extend_select_to.clone();
+        let get_render_index_from_id = state.get_render_index_from_id.clone();
+        let get_id_from_render_index = state.get_id_from_render_index.clone();
+        let select_many = state.select_many;
+        let set_page = state.pagination.functions.set_page.clone();
+        let focused_data = state.focused_data.clone();
+        let on_row_activate = props.on_row_activate;
+        let on_column_resize = props.on_column_resize;
+        let column_option_map = state.column_option_map.clone();
+        let column_widths = state.column_widthes.get.clone();
+        let set_width_by_key = state.column_widthes.set_by_key.clone();
+        let toggle_group = state.row_grouping.toggle.clone();
+        let set_tree_expanded = state.tree_data.set_expanded.clone();
+        let tree_key = tree_key.clone();
+        let is_editing = cell_edit.editing.is_some();
+        let start_edit = cell_edit.start.clone();
+        let column_keys = column_keys.clone();
+
+        move |event: KeyboardEvent| {
+            // Keys typed into a cell editor are handled by the editor
+            if event.data().is_composing() || is_editing {
+                return;
             }
-            Key::Enter => {
-                event.prevent_default();
-                // Enter key handling would go here
+
+            // Enter / F2 edit the focused cell of an editable column
+            let focused_edit = focused_data.as_ref()
+                .filter(|data| data.group.is_none())
+                .zip(focus.column.as_ref().and_then(|key| column_option_map.get(key)))
+                .and_then(|(data, column)| Some(CellEdit::new(
+                    data.id.clone(),
+                    column.key.clone(),
+                    column.editor.clone()?,
+                    get_cell_text(column, &data.get, &data.id, data.data_index),
+                )));
+            let focused_group_id = focused_data.as_ref()
+                .filter(|data| data.group.is_some())
+                .map(|data| data.id.clone());
+            // ArrowLeft / ArrowRight expand and collapse tree rows on the toggle column
+            let focused_tree = focused_data.as_ref()
+                .filter(|_| focus.column.is_none() || focus.column == tree_key)
+                .and_then(|data| Some((data.id.clone(), data.tree.clone()?)));
+            let focused_column = focus.column.as_ref().and_then(|key| column_option_map.get(key));
+
+            let key = event.key();
+            let Some(action) = get_key_action(&KeyInput {
+                ctrl: event.modifiers().ctrl() || event.modifiers().meta(),
+                shift: event.modifiers().shift(),
+                alt: event.modifiers().alt(),
+                arrow_left_actionable: key == Key::ArrowLeft && is_arrow_left_actionable(&event),
+                arrow_right_actionable: key == Key::ArrowRight && is_arrow_right_actionable(&event),
+                select_many,
+                has_focused_column: focused_column.is_some(),
+                has_focused_edit: focused_edit.is_some(),
+                is_group_row: focused_group_id.is_some(),
+                tree: focused_tree.as_ref().map(|(_, tree)| tree.clone()),
+                key,
+            }) else {
+                return;
+            };
+            event.prevent_default();
+
+            match action {
+                KeyAction::ExtendSelection(step) => {
+                    let target_id = (get_render_index_from_id)(focus.id.clone())
+                        .and_then(|index| index.checked_add_signed(step))
+                        .and_then(|index| (get_id_from_render_index)(Some(index)));
+                    if let Some(target_id) = target_id {
+                        focus.set_by_id.borrow_mut()(SetStateAction::Value(Some(target_id.clone())), without_select());
+                        extend_select_to.borrow_mut()(target_id);
+                    }
+                }
+                KeyAction::MoveRow(step) => {
+                    select.set_anchor_id.borrow_mut()(None);
+                    focus.set_by_render_index.borrow_mut()(SetStateAction::Function(Rc::new(move |index: usize| index.saturating_add_signed(step))), None);
+                }
+                KeyAction::ResizeColumn(step) => {
+                    let Some(column) = focused_column else {
+                        return;
+                    };
+                    let width_px = column_widths.get(&column.key)
+                        .cloned()
+                        .flatten()
+                        .and_then(|width| parse_px_width(&width))
+                        .or_else(|| header_widths.peek().get(&column.key).copied())
+                        .unwrap_or(column.min_width_px);
+                    let width = Some(format!("{}px", column.clamp_width_px(width_px + step)));
+                    set_width_by_key.borrow_mut()(column.key.clone(), width.clone());
+                    if let Some(on_column_resize) = on_column_resize {
+                        on_column_resize.call(ColumnResizeEvent { key: column.key.clone(), width });
+                    }
+                }
+                KeyAction::SetTreeExpanded(is_expanded) => {
+                    if let Some((id, _)) = focused_tree {
+                        set_tree_expanded.borrow_mut()(id, is_expanded);
+                    }
+                }
+                KeyAction::FocusParent => {
+                    let parent_id = focused_tree.and_then(|(_, tree)| tree.parent_id);
+                    focus.set_by_id.borrow_mut()(SetStateAction::Value(parent_id), None);
+                }
+                KeyAction::MoveColumn(step) => {
+                    focus.set_column.borrow_mut()(get_shifted_column_key(&column_keys, focus.column.as_ref(), step));
+                }
+                KeyAction::PreviousPage => {
+                    set_page.borrow_mut()(SetStateAction::Function(Rc::new(|page: usize| page.saturating_sub(1))));
+                }
+                KeyAction::NextPage => {
+                    set_page.borrow_mut()(SetStateAction::Function(Rc::new(move |page: usize| (page + 1).min(max_page))));
+                }
+                KeyAction::FirstRow | KeyAction::LastRow => {
+                    select.set_anchor_id.borrow_mut()(None);
+                    let index = if action == KeyAction::FirstRow { 0 } else { usize::MAX };
+                    focus.set_by_render_index.borrow_mut()(SetStateAction::Value(index), None);
+                }
+                KeyAction::FirstRowOfPage | KeyAction::LastRowOfPage => {
+                    select.set_anchor_id.borrow_mut()(None);
+                    let index = if action == KeyAction::FirstRowOfPage { 0 } else { usize::MAX };
+                    focus.set_by_local_index.borrow_mut()(SetStateAction::Value(Some(index)), None);
+                }
+                KeyAction::StartEdit => {
+                    if let Some(edit) = focused_edit {
+                        start_edit.borrow_mut()(edit);
+                    }
+                }
+                KeyAction::ToggleGroup => {
+                    toggle_group.borrow_mut()(focused_group_id.unwrap_or_default());
+                }
+                KeyAction::ActivateRow => {
+                    if let (Some(on_row_activate), Some(data)) = (on_row_activate, focused_data.clone()) {
+                        on_row_activate.call(data);
+                    }
+                }
+                KeyAction::ToggleSelect => {
+                    if let Some(id) = focus.id.clone() {
+                        select.set_anchor_id.borrow_mut()(Some(id.clone()));
+                        select.toggle_by_id.borrow_mut()(id);
+                    }
+                }
+                KeyAction::SelectAll => {
+                    select.select_all.borrow_mut()();
+                }
             }
-            Key::Character(ch) if ch == " " => {
-                event.prevent_default();
-                // Space key handling would go here
+        }
+    };
+
+    let handle_scroll = {
+        let set_offset_on_scroll = state.virtual_scroll.set_offset_on_scroll.clone();
+        let set_scroll_left = state.column_virtualization.set_scroll_left.clone();
+        move |_event: ScrollEvent| {
+            let Some(element) = scroll_element.read().clone() else {
+                return;
+            };
+            let set_offset_on_scroll = set_offset_on_scroll.clone();
+            let set_scroll_left = set_scroll_left.clone();
+            spawn(async move {
+                if let Ok(offset) = element.get_scroll_offset().await {
+                    set_offset_on_scroll.borrow_mut()(offset.y);
+                    set_scroll_left.borrow_mut()(offset.x);
+                }
+            });
+        }
+    };
+
+    // Bundle parts laid out the same in every row
+    let row_parts = Rc::new(placed_bundles
+        .iter()
+        .flat_map(|bundle| bundle.parts(&state.column_virtualization, &pinned_offsets))
+        .filter(|part| part.column_count > 0)
+        .map(RowPart::from)
+        .collect::<Vec<_>>());
+
+    // Measure the rendered rows for the virtual scroll
+    let on_row_mounted = |row: &DataWithId<T>| {
+        let set_render_cache = (state.virtual_scroll.set_render_cache)(row.local_index.unwrap_or(0));
+        move |event: MountedEvent| set_render_cache.borrow_mut()(Some(event.data()))
+    };
+    let on_row_resize = |row: &DataWithId<T>| {
+        let set_render_height = (state.virtual_scroll.set_render_height)(row.local_index.unwrap_or(0));
+        move |event: ResizeEvent| {
+            if let Ok(size) = event.get_border_box_size() {
+                set_render_height.borrow_mut()(size.height);
             }
-            _ => {}
+        }
+    };
+    let on_column_resize = props.on_column_resize;
+
+    let set_previous_page = {
+        let set_page = state.pagination.functions.set_page.clone();
+        move |_| {
+            set_page.borrow_mut()(SetStateAction::Function(Rc::new(|page: usize| page.saturating_sub(1))));
+        }
+    };
+
+    let set_next_page = {
+        let set_page = state.pagination.functions.set_page.clone();
+        move |_| {
+            set_page.borrow_mut()(SetStateAction::Function(Rc::new(move |page: usize| (page + 1).min(max_page))));
         }
     };
 
     rsx! {
+        ColumnBundleStyles {}
+
         div {
             "data-testid": "TableView",
             class: "{table_class}",
             style: "{table_style}",
             tabindex: props.tab_index.unwrap_or(0),
             onkeydown: handle_key_down,
-            
+            onmounted: move |event: MountedEvent| table_element.set(Some(event.data())),
+
             // Title section
-            if let Some(title) = &props.title {
+            if props.title.is_some() || state.search.is_some() {
                 div {
                     class: "Header",
-                    div {
-                        class: "Title",
-                        "{title}"
+                    if let Some(title) = &props.title {
+                        div {
+                            class: "Title",
+                            "{title}"
+                        }
+                    }
+                    if let Some(search) = &state.search {
+                        input {
+                            class: "Search",
+                            r#type: "search",
+                            placeholder: "Search",
+                            value: "{search.input}",
+                            oninput: {
+                                let set_query = search.set_query.clone();
+                                move |event: FormEvent| set_query.borrow_mut()(event.value())
+                            },
+                            // Keep typing in the search box from driving table navigation
+                            onkeydown: |event| event.stop_propagation(),
+                        }
                     }
                 }
             }
-            
+
             // Table content
             div {
                 class: "Grid",
-                style: "overflow: auto;",
-                
+                "aria-busy": state.is_loading,
+                style: "overflow: auto; display: grid; grid-template-columns: {grid_template_columns};",
+                onmounted: {
+                    let set_view_cache = state.virtual_scroll.set_view_cache.clone();
+                    move |event: MountedEvent| {
+                        set_view_cache.borrow_mut()(Some(event.data()));
+                        scroll_element.set(Some(event.data()));
+                    }
+                },
+                onresize: {
+                    let set_view_height = state.virtual_scroll.set_view_height.clone();
+                    let set_view_width = state.column_virtualization.set_view_width.clone();
+                    move |event: ResizeEvent| {
+                        if let Ok(size) = event.get_content_box_size() {
+                            set_view_height.borrow_mut()(size.height);
+                            set_view_width.borrow_mut()(size.width);
+                        }
+                    }
+                },
+                onscroll: handle_scroll,
+
                 // Header row
-                if !props.headers.is_empty() {
-                    div {
-                        class: "GridHeaderRow",
-                        for header in &props.headers {
-                            div {
-                                class: "HeaderCell",
-                                "{header}"
+                GridRow {
+                    column_count,
+                    parts: row_parts.clone(),
+                    class: "GridHeaderRow",
+                    style: "position: sticky; top: 0; z-index: 2; background: var(--background-color, Canvas);",
+                    onresize: move |event: ResizeEvent| {
+                        if let Ok(size) = event.get_border_box_size() {
+                            if *header_row_height_px.peek() != size.height {
+                                header_row_height_px.set(size.height);
+                            }
+                        }
+                    },
+                    render_cell: {
+                        let state = state.clone();
+                        let tail_key = tail_key.clone();
+                        move |key: String| {
+                            let Some(column) = state.column_option_map.get(&key) else {
+                                return VNode::empty();
+                            };
+                            rsx! {
+                                HeaderCell {
+                                    hidden: column.is_hidden,
+                                    sort_order_is_changeable: column.sort_order_is_changeable,
+                                    sort_order: state.sort.order_map.get(&column.key).cloned().or(Some(Order::None)),
+                                    shift_sort_order: {
+                                        let shift_order = state.sort.shift_order.clone();
+                                        let key = column.key.clone();
+                                        move |step: usize| {
+                                            (shift_order)(key.clone())(SetStateAction::Function(Rc::new(move |index: usize| index + step)));
+                                        }
+                                    },
+                                    toggle_sort_order_and_apply_once: {
+                                        let set_order_once = state.sort.set_order_once.clone();
+                                        let key = column.key.clone();
+                                        move |_| {
+                                            (set_order_once)(key.clone()).borrow_mut()(SetStateAction::Function(Rc::new(|order: Order| {
+                                                match order {
+                                                    Order::Asc => Order::Desc,
+                                                    _ => Order::Asc,
+                                                }
+                                            })));
+                                        }
+                                    },
+                                    set_width: {
+                                        let set_by_key = state.column_widthes.set_by_key.clone();
+                                        let key = column.key.clone();
+                                        move |width: Option<String>| {
+                                            set_by_key.borrow_mut()(key.clone(), width);
+                                        }
+                                    },
+                                    on_resize: {
+                                                let key = column.key.clone();
+                                        move |width: Option<String>| {
+                                            if let Some(on_column_resize) = on_column_resize {
+                                                on_column_resize.call(ColumnResizeEvent { key: key.clone(), width });
+                                            }
+                                        }
+                                    },
+                                    on_measure: {
+                                        let key = column.key.clone();
+                                        move |width_px: f64| {
+                                            header_widths.write().insert(key.clone(), width_px);
+                                        }
+                                    },
+                                    min_width_px: column.min_width_px,
+                                    max_width_px: column.max_width_px,
+                                    on_drag_start: {
+                                        let key = column.key.clone();
+                                        move |_| dragged_column.set(Some(key.clone()))
+                                    },
+                                    on_drop: {
+                                        let move_column = state.column_order.move_column.clone();
+                                        let order = state.column_order.order.clone();
+                                        let key = column.key.clone();
+                                        move |_| {
+                                            let Some(dragged) = dragged_column.take() else {
+                                                return;
+                                            };
+                                            if let Some(to_index) = order.iter().position(|it| *it == key) {
+                                                move_column.borrow_mut()(dragged, to_index);
+                                            }
+                                        }
+                                    },
+                                    focused: state.focus.column.as_ref() == Some(&column.key),
+                                    is_tail: tail_key.as_ref() == Some(&column.key),
+                                    "{column.label}"
+                                }
                             }
                         }
+                    },
+                }
+
+                // Filter row
+                if has_filter {
+                    GridRow {
+                        column_count,
+                        parts: row_parts.clone(),
+                        class: "GridFilterRow",
+                        style: "position: sticky; top: {header_row_height_px}px; z-index: 2; background: var(--background-color, Canvas);",
+                        render_cell: {
+                            let state = state.clone();
+                            move |key: String| {
+                                let Some(column) = state.column_option_map.get(&key) else {
+                                    return VNode::empty();
+                                };
+                                rsx! {
+                                    FilterCell {
+                                        hidden: column.is_hidden,
+                                        kind: column.filter.clone(),
+                                        value: state.filter.filter_map.get(&column.key).cloned(),
+                                        options: if matches!(column.filter, Some(FilterKind::Set)) {
+                                            (state.get_filter_options)(&column.key)
+                                        } else {
+                                            Vec::new()
+                                        },
+                                        set_value: {
+                                            let set_filter = state.filter.set_filter.clone();
+                                            let key = column.key.clone();
+                                            move |filter: Option<FilterValue>| {
+                                                (set_filter)(key.clone()).borrow_mut()(filter);
+                                            }
+                                        },
+                                    }
+                                }
+                            }
+                        },
                     }
                 }
-                
+
                 // Data rows
                 div {
-                    class: "VirtualGrid",
-                    for (row_index, row) in props.data.iter().enumerate() {
-                        div {
-                            key: "{row_index}",
-                            class: if row_index % 2 == 0 { "ContentRow" } else { "ContentRow Even" },
-                            for (col_index, cell) in row.iter().enumerate() {
+                    style: "grid-column: 1 / -1; height: {before_height_px}px;",
+                }
+                for row in state.virtualized_data.iter() {
+                    if let Some(group) = &row.group {
+                        GridRow {
+                            key: "{row.id}",
+                            column_count,
+                            parts: row_parts.clone(),
+                            aria_expanded: group.is_expanded,
+                            class: if state.focus.id.as_ref() == Some(&row.id) { "ContentRow GroupRow focused" } else { "ContentRow GroupRow" },
+                            onmounted: on_row_mounted(row),
+                            onresize: on_row_resize(row),
+                            render_cell: {
+                                let state = state.clone();
+                                let row = row.clone();
+                                let group = group.clone();
+                                let head_key = head_key.clone();
+                                move |key: String| {
+                                    let Some(column) = state.column_option_map.get(&key) else {
+                                        return VNode::empty();
+                                    };
+                                    rsx! {
+                                        DataCell {
+                                            hidden: column.is_hidden,
+                                            focused: state.focus.id.as_ref() == Some(&row.id) && state.focus.column.as_ref() == Some(&column.key),
+                                            align: column.align.clone(),
+                                            class: "group-cell",
+                                            onclick: {
+                                                let set_by_id = state.focus.set_by_id.clone();
+                                                let set_column = state.focus.set_column.clone();
+                                                let id = row.id.clone();
+                                                let key = column.key.clone();
+                                                move |_| {
+                                                    set_by_id.borrow_mut()(SetStateAction::Value(Some(id.clone())), None);
+                                                    set_column.borrow_mut()(Some(key.clone()));
+                                                }
+                                            },
+                                            if head_key.as_ref() == Some(&column.key) {
+                                                span {
+                                                    style: "padding-left: {row.depth}em;",
+                                                    button {
+                                                        class: "group-toggle",
+                                                        r#type: "button",
+                                                        tabindex: -1,
+                                                        onclick: {
+                                                            let toggle = state.row_grouping.toggle.clone();
+                                                            let id = row.id.clone();
+                                                            move |event: MouseEvent| {
+                                                                event.stop_propagation();
+                                                                toggle.borrow_mut()(id.clone());
+                                                            }
+                                                        },
+                                                        if group.is_expanded { "▼" } else { "▶" }
+                                                    }
+                                                    {
+                                                        let label = state.column_option_map.get(&group.key)
+                                                            .map(|column| column.label.clone())
+                                                            .unwrap_or_default();
+                                                        format!(" {label}: {} ({})", group.value, group.count)
+                                                    }
+                                                }
+                                            } else if column.total {
+                                                {
+                                                    state.group_totals.get(&row.id)
+                                                        .and_then(|totals| totals.get(&column.key))
+                                                        .map(|total| format_total(*total))
+                                                        .unwrap_or_default()
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            },
+                        }
+                    } else {
+                        GridRow {
+                            key: "{row.id}",
+                            column_count,
+                            parts: row_parts.clone(),
+                            aria_expanded: row.tree.as_ref().filter(|tree| tree.has_children).map(|tree| tree.is_expanded),
+                            class: {
+                                let mut classes = vec!["ContentRow"];
+                                if row.render_index.unwrap_or(0) % 2 == 1 {
+                                    classes.push("Even");
+                                }
+                                if state.focus.id.as_ref() == Some(&row.id) {
+                                    classes.push("focused");
+                                }
+                                if state.select.ids.contains(&row.id) {
+                                    classes.push("selected");
+                                }
+                                if render_detail.is_some() && state.row_expansion.is_expanded(&row.id) {
+                                    classes.push("expanded");
+                                }
+                                classes.join(" ")
+                            },
+                            onmounted: on_row_mounted(row),
+                            onresize: on_row_resize(row),
+                            render_cell: {
+                                let state = state.clone();
+                                let row = row.clone();
+                                let head_key = head_key.clone();
+                                let tree_key = tree_key.clone();
+                                let cell_edit = cell_edit.clone();
+                                let column_keys = column_keys.clone();
+                                move |key: String| {
+                                    let Some(column) = state.column_option_map.get(&key) else {
+                                        return VNode::empty();
+                                    };
+                                    rsx! {
+                                        DataCell {
+                                            hidden: column.is_hidden,
+                                            focused: state.focus.id.as_ref() == Some(&row.id) && state.focus.column.as_ref() == Some(&column.key),
+                                            align: column.align.clone(),
+                                            // Indent grouped rows under their group rows, and tree rows under their parents
+                                            style: {
+                                                let indent_key = if row.tree.is_some() { &tree_key } else { &head_key };
+                                                (row.depth > 0 && indent_key.as_ref() == Some(&column.key))
+                                                    .then(|| format!("padding-left: calc(0.5em + {}em);", row.depth))
+                                            },
+                                            onclick: {
+                                                let set_by_id = state.focus.set_by_id.clone();
+                                                let set_column = state.focus.set_column.clone();
+                                                let select = state.select.clone();
+                                                let extend_select_to = state.extend_select_to.clone();
+                                                let id = row.id.clone();
+                                                let key = column.key.clone();
+                                                move |event: MouseEvent| {
+                                                    let modifiers = event.modifiers();
+                                                    if modifiers.shift() {
+                                                        // Shift+Click extends the selection from the anchor
+                                                        set_by_id.borrow_mut()(SetStateAction::Value(Some(id.clone())), without_select());
+                                                        extend_select_to.borrow_mut()(id.clone());
+                                                    } else if modifiers.ctrl() || modifiers.meta() {
+                                                        // Ctrl+Click toggles the row and moves the anchor
+                                                        set_by_id.borrow_mut()(SetStateAction::Value(Some(id.clone())), without_select());
+                                                        select.set_anchor_id.borrow_mut()(Some(id.clone()));
+                                                        select.toggle_by_id.borrow_mut()(id.clone());
+                                                    } else {
+                                                        select.set_anchor_id.borrow_mut()(None);
+                                                        set_by_id.borrow_mut()(SetStateAction::Value(Some(id.clone())), None);
+                                                    }
+                                                    set_column.borrow_mut()(Some(key.clone()));
+                                                }
+                                            },
+                                            if render_detail.is_some() && head_key.as_ref() == Some(&column.key) {
+                                                button {
+                                                    class: "detail-toggle",
+                                                    r#type: "button",
+                                                    tabindex: -1,
+                                                    "aria-expanded": state.row_expansion.is_expanded(&row.id),
+                                                    onclick: {
+                                                        let toggle = state.row_expansion.toggle.clone();
+                                                        let id = row.id.clone();
+                                                        move |event: MouseEvent| {
+                                                            event.stop_propagation();
+                                                            toggle.borrow_mut()(id.clone());
+                                                        }
+                                                    },
+                                                    if state.row_expansion.is_expanded(&row.id) { "−" } else { "+" }
+                                                }
+                                            }
+                                            if let (Some(tree), true) = (&row.tree, tree_key.as_ref() == Some(&column.key)) {
+                                                if tree.has_children {
+                                                    button {
+                                                        class: "tree-toggle",
+                                                        r#type: "button",
+                                                        tabindex: -1,
+                                                        disabled: tree.is_loading,
+                                                        onclick: {
+                                                            let toggle = state.tree_data.toggle.clone();
+                                                            let id = row.id.clone();
+                                                            move |event: MouseEvent| {
+                                                                event.stop_propagation();
+                                                                toggle.borrow_mut()(id.clone());
+                                                            }
+                                                        },
+                                                        if tree.is_loading { "…" } else if tree.is_expanded { "▼" } else { "▶" }
+                                                    }
+                                                } else {
+                                                    // Keeps leaves aligned with their expandable siblings
+                                                    span { class: "tree-toggle-spacer", style: "display: inline-block; width: 1.5em;" }
+                                                }
+                                            }
+                                            if let Some(edit) = cell_edit.editing.as_ref().filter(|edit| edit.is_editing(&row.id, &column.key)) {
+                                                CellEditor {
+                                                    kind: edit.kind.clone(),
+                                                    value: edit.value.clone(),
+                                                    error: edit.error.clone(),
+                                                    on_input: {
+                                                        let set_value = cell_edit.set_value.clone();
+                                                        move |value: String| set_value.borrow_mut()(value)
+                                                    },
+                                                    on_commit: {
+                                                        let commit = cell_edit.commit.clone();
+                                                        let set_column = state.focus.set_column.clone();
+                                                        let column_keys = column_keys.clone();
+                                                        let key = column.key.clone();
+                                                        move |step: isize| {
+                                                            if !commit.borrow_mut()() {
+                                                                return;
+                                                            }
+                                                            if step != 0 {
+                                                                set_column.borrow_mut()(get_shifted_column_key(&column_keys, Some(&key), step));
+                                                            }
+                                                            focus_table();
+                                                        }
+                                                    },
+                                                    on_cancel: {
+                                                        let cancel = cell_edit.cancel.clone();
+                                                        move |_| {
+                                                            cancel.borrow_mut()();
+                                                            focus_table();
+                                                        }
+                                                    },
+                                                }
+                                            } else {
+                                                {
+                                                    let text = (column.value_mapper)(&row.get as &dyn Any, &RenderOptions {
+                                                        data: row.get.clone(),
+                                                        id: row.id.clone(),
+                                                        is_focused: state.focus.id.as_ref() == Some(&row.id),
+                                                        focused_column: state.focus.column.clone().filter(|_| state.focus.id.as_ref() == Some(&row.id)),
+                                                        is_selected: state.select.ids.contains(&row.id),
+                                                        data_index: row.data_index,
+                                                        render_index: row.render_index.unwrap_or(0),
+                                                        local_index: row.local_index.unwrap_or(0),
+                                                    });
+                                                    let ranges = state.search.as_ref()
+                                                        .and_then(|search| search.find(&text))
+                                                        .map(|text_match| text_match.ranges)
+                                                        .unwrap_or_default();
+                                                    rsx! { HighlightedText { text, ranges } }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            },
+                            // The panel fills a second grid row of the row, so the virtual scroll measures
+                            // it with the row and focus moves over it to the next row
+                            if let (Some(render_detail), true) = (render_detail, state.row_expansion.is_expanded(&row.id)) {
                                 div {
-                                    key: "{col_index}",
-                                    class: "DataCell",
-                                    "{cell}"
+                                    class: "DetailPanel",
+                                    style: "grid-column: 1 / -1;",
+                                    onkeydown: |event| event.stop_propagation(),
+                                    {render_detail.call(row.clone())}
                                 }
                             }
                         }
                     }
                 }
+                div {
+                    style: "grid-column: 1 / -1; height: {after_height_px}px;",
+                }
+
+                // Totals row
+                if has_total {
+                    GridRow {
+                        column_count,
+                        parts: row_parts.clone(),
+                        class: "GridFooterRow",
+                        style: "position: sticky; bottom: 0; z-index: 2; background: var(--background-color, Canvas);",
+                        render_cell: {
+                            let state = state.clone();
+                            move |key: String| {
+                                let Some(column) = state.column_option_map.get(&key) else {
+                                    return VNode::empty();
+                                };
+                                rsx! {
+                                    DataCell {
+                                        hidden: column.is_hidden,
+                                        align: column.align.clone(),
+                                        class: "total-cell",
+                                        if column.total {
+                                            {state.totals.get(&column.key).map(|total| format_total(*total)).unwrap_or_default()}
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                    }
+                }
             }
-            
-            // Paginator placeholder
+
+            // Paginator
             div {
                 class: "Paginator",
                 button {
-                    onclick: move |_| {
-                        // Previous page logic would go here
-                    },
+                    disabled: page == state.pagination.state.min_page,
+                    onclick: set_previous_page,
                     "Previous"
                 }
                 span {
                     class: "PageInfo",
-                    "Page 1 / 1"
+                    "Page {page + 1} / {max_page + 1}"
                 }
                 button {
-                    onclick: move |_| {
-                        // Next page logic would go here
-                    },
+                    disabled: page >= max_page,
+                    onclick: set_next_page,
                     "Next"
                 }
             }
@@ -140,4 +1192,261 @@ pub fn TableView(props: TableViewProps) -> Element {
 }
 
 // Export for backward compatibility with existing code that may import complex types
-pub use super::use_table::TableViewStateProps;
\ No newline at end of file
+pub use super::use_table::TableViewStateProps;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::render_until_idle;
+    use std::cell::RefCell;
+    use crate::components::table_view::get_column_option_map::{default_column_option, ColumnOptionArgs, RenderMapValue};
+    use crate::components::table_view::use_table::{use_table, DefaultPagination, UseTableParams};
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestData {
+        id: i32,
+        name: String,
+    }
+
+    fn create_column(key: &str, is_row_header: bool, is_hidden: bool) -> ColumnOption<TestData> {
+        ColumnOption {
+            is_row_header,
+            is_hidden,
+            ..default_column_option(key)
+        }
+    }
+
+    #[test]
+    fn test_get_placed_column_bundles() {
+        let bundles = get_placed_column_bundles(vec![
+            create_column("id", true, false),
+            create_column("name", false, false),
+            create_column("memo", false, true),
+            create_column("group", true, false),
+            create_column("value", false, false),
+        ]);
+
+        assert_eq!(bundles.len(), 2);
+        assert_eq!(bundles[0].column_start, 0);
+        assert_eq!(bundles[0].header_count(), 1);
+        assert_eq!(bundles[0].content_count(), 1);
+        assert_eq!(bundles[1].column_start, 2);
+        assert_eq!(bundles[1].content_start, 1);
+        assert_eq!(bundles[1].header_count(), 1);
+        assert_eq!(bundles[1].content_count(), 1);
+    }
+
+    #[test]
+    fn test_bundle_parts_pinned() {
+        let pinned = |key: &str, pin: Pin| ColumnOption {
+            pinned: Some(pin),
+            ..create_column(key, false, false)
+        };
+        let bundles = get_placed_column_bundles(vec![
+            pinned("code", Pin::Left),
+            create_column("id", true, false),
+            create_column("name", false, false),
+            create_column("memo", false, false),
+            pinned("total", Pin::Right),
+        ]);
+        let column_virtualization = UseColumnVirtualizationResult {
+            is_enabled: true,
+            width_px_map: HashMap::new(),
+            window: 0..1,
+            set_scroll_left: Rc::new(RefCell::new(|_| {})),
+            set_view_width: Rc::new(RefCell::new(|_| {})),
+        };
+        let pinned_offsets = get_pinned_offsets(&[
+            ("code".to_string(), Some(Pin::Left), 50.0),
+            ("id".to_string(), Some(Pin::Left), 80.0),
+            ("name".to_string(), None, 100.0),
+            ("memo".to_string(), None, 100.0),
+            ("total".to_string(), Some(Pin::Right), 60.0),
+        ]);
+
+        assert_eq!(bundles[1].content_start, 0);
+        let parts = bundles[1].parts(&column_virtualization, &pinned_offsets);
+        let summary: Vec<(usize, usize, usize, Option<PinnedOffset>)> = parts
+            .iter()
+            .map(|part| (part.column_start, part.columns.len(), part.skipped_after, part.pinned))
+            .collect();
+
+        assert_eq!(summary, vec![
+            (1, 1, 0, Some(PinnedOffset { pin: Pin::Left, offset_px: 50.0 })),
+            (2, 1, 1, None),
+            (4, 1, 0, Some(PinnedOffset { pin: Pin::Right, offset_px: 0.0 })),
+        ]);
+    }
+
+    #[test]
+    fn test_table_view_render() {
+        let mut dom = VirtualDom::new(|| {
+            let mut render_map = HashMap::new();
+            render_map.insert("id".to_string(), RenderMapValue::Options(ColumnOptionArgs {
+                label: "ID".to_string(),
+                value_mapper: Some(Rc::new(|raw: &dyn Any, _options: &RenderOptions<TestData>| {
+                    raw.downcast_ref::<TestData>().map(|data| data.id.to_string()).unwrap_or_default()
+                })),
+                is_row_header: Some(true),
+                align: Some("right".to_string()),
+                filter: Some(FilterKind::NumberRange),
+                ..Default::default()
+            }));
+            render_map.insert("name".to_string(), RenderMapValue::Label("Name".to_string()));
+
+            let table = use_table(UseTableParams {
+                data: vec![
+                    TestData { id: 1, name: "Alice".to_string() },
+                    TestData { id: 2, name: "Bob".to_string() },
+                ],
+                render_map,
+                get_data_id: Some(Rc::new(|item: &TestData, _| format!("item-{}", item.id))),
+                default_pagination: Some(DefaultPagination {
+                    limit: Some(10),
+                    auto_limit: Some(false),
+                }),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+
+            assert_eq!(table.props.state_props.virtualized_data.len(), 2);
+
+            rsx! {
+                TableView {
+                    state_props: table.props.state_props,
+                    title: "Users".to_string(),
+                    on_row_activate: move |row: DataWithId<TestData>| assert!(!row.id.is_empty()),
+                }
+            }
+        });
+
+        dom.rebuild_to_vec();
+    }
+
+    #[tokio::test]
+    async fn test_table_view_render_detail() {
+
+        type Rendered = Rc<RefCell<Vec<String>>>;
+
+        fn app(rendered: Rendered) -> Element {
+            let mut render_map = HashMap::new();
+            render_map.insert("name".to_string(), RenderMapValue::Label("Name".to_string()));
+
+            let table = use_table(UseTableParams {
+                data: vec![
+                    TestData { id: 1, name: "Alice".to_string() },
+                    TestData { id: 2, name: "Bob".to_string() },
+                ],
+                render_map,
+                get_data_id: Some(Rc::new(|item: &TestData, _| format!("item-{}", item.id))),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+
+            let expand = table.row_expansion.expand.clone();
+            use_effect(move || {
+                expand.borrow_mut()("item-2".to_string());
+            });
+
+            rsx! {
+                TableView {
+                    state_props: table.props.state_props,
+                    render_detail: move |row: DataWithId<TestData>| {
+                        rendered.borrow_mut().push(row.id.clone());
+                        rsx! { p { "{row.get.name}" } }
+                    },
+                }
+            }
+        }
+
+        let rendered: Rendered = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(app, rendered.clone());
+        dom.rebuild_in_place();
+        render_until_idle(&mut dom).await;
+
+        // Only the expanded row renders its panel
+        assert!(!rendered.borrow().is_empty());
+        assert!(rendered.borrow().iter().all(|id| id == "item-2"));
+    }
+
+    fn key_input(key: Key) -> KeyInput {
+        KeyInput {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+            arrow_left_actionable: true,
+            arrow_right_actionable: true,
+            select_many: true,
+            has_focused_column: false,
+            has_focused_edit: false,
+            is_group_row: false,
+            tree: None,
+        }
+    }
+
+    #[test]
+    fn test_get_key_action_navigation() {
+        assert_eq!(get_key_action(&key_input(Key::ArrowUp)), Some(KeyAction::MoveRow(-1)));
+        assert_eq!(get_key_action(&key_input(Key::ArrowDown)), Some(KeyAction::MoveRow(1)));
+        assert_eq!(get_key_action(&KeyInput { shift: true, ..key_input(Key::ArrowUp) }), Some(KeyAction::ExtendSelection(-1)));
+        assert_eq!(get_key_action(&KeyInput { shift: true, ..key_input(Key::ArrowDown) }), Some(KeyAction::ExtendSelection(1)));
+        assert_eq!(get_key_action(&key_input(Key::ArrowLeft)), Some(KeyAction::MoveColumn(-1)));
+        assert_eq!(get_key_action(&key_input(Key::ArrowRight)), Some(KeyAction::MoveColumn(1)));
+        // The cursor of an input moves instead
+        assert_eq!(get_key_action(&KeyInput { arrow_left_actionable: false, ..key_input(Key::ArrowLeft) }), None);
+        assert_eq!(get_key_action(&KeyInput { arrow_right_actionable: false, ..key_input(Key::ArrowRight) }), None);
+        assert_eq!(get_key_action(&key_input(Key::Home)), Some(KeyAction::FirstRowOfPage));
+        assert_eq!(get_key_action(&key_input(Key::End)), Some(KeyAction::LastRowOfPage));
+        assert_eq!(get_key_action(&KeyInput { ctrl: true, ..key_input(Key::Home) }), Some(KeyAction::FirstRow));
+        assert_eq!(get_key_action(&KeyInput { ctrl: true, ..key_input(Key::End) }), Some(KeyAction::LastRow));
+        assert_eq!(get_key_action(&key_input(Key::PageUp)), Some(KeyAction::PreviousPage));
+        assert_eq!(get_key_action(&key_input(Key::PageDown)), Some(KeyAction::NextPage));
+        assert_eq!(get_key_action(&key_input(Key::Tab)), None);
+    }
+
+    #[test]
+    fn test_get_key_action_column_resize() {
+        let alt_left = KeyInput { alt: true, ..key_input(Key::ArrowLeft) };
+
+        assert_eq!(get_key_action(&alt_left), None);
+        assert_eq!(get_key_action(&KeyInput { has_focused_column: true, ..alt_left.clone() }), Some(KeyAction::ResizeColumn(-KEYBOARD_RESIZE_STEP_PX)));
+        assert_eq!(
+            get_key_action(&KeyInput { alt: true, has_focused_column: true, ..key_input(Key::ArrowRight) }),
+            Some(KeyAction::ResizeColumn(KEYBOARD_RESIZE_STEP_PX)),
+        );
+    }
+
+    #[test]
+    fn test_get_key_action_tree() {
+        let tree = |has_children, is_expanded, parent_id: Option<&str>| Some(TreeNode {
+            parent_id: parent_id.map(str::to_string),
+            has_children,
+            is_expanded,
+            is_loading: false,
+        });
+
+        assert_eq!(get_key_action(&KeyInput { tree: tree(true, false, None), ..key_input(Key::ArrowRight) }), Some(KeyAction::SetTreeExpanded(true)));
+        assert_eq!(get_key_action(&KeyInput { tree: tree(true, true, None), ..key_input(Key::ArrowRight) }), Some(KeyAction::MoveColumn(1)));
+        assert_eq!(get_key_action(&KeyInput { tree: tree(true, true, Some("a")), ..key_input(Key::ArrowLeft) }), Some(KeyAction::SetTreeExpanded(false)));
+        assert_eq!(get_key_action(&KeyInput { tree: tree(false, false, Some("a")), ..key_input(Key::ArrowLeft) }), Some(KeyAction::FocusParent));
+        assert_eq!(get_key_action(&KeyInput { tree: tree(false, false, None), ..key_input(Key::ArrowLeft) }), Some(KeyAction::MoveColumn(-1)));
+    }
+
+    #[test]
+    fn test_get_key_action_enter_and_space() {
+        let space = || key_input(Key::Character(" ".to_string()));
+
+        assert_eq!(get_key_action(&key_input(Key::Enter)), Some(KeyAction::ActivateRow));
+        assert_eq!(get_key_action(&key_input(Key::F2)), None);
+        assert_eq!(get_key_action(&KeyInput { has_focused_edit: true, ..key_input(Key::Enter) }), Some(KeyAction::StartEdit));
+        assert_eq!(get_key_action(&KeyInput { has_focused_edit: true, ..key_input(Key::F2) }), Some(KeyAction::StartEdit));
+        assert_eq!(get_key_action(&KeyInput { is_group_row: true, ..key_input(Key::Enter) }), Some(KeyAction::ToggleGroup));
+        assert_eq!(get_key_action(&KeyInput { is_group_row: true, ..space() }), Some(KeyAction::ToggleGroup));
+        assert_eq!(get_key_action(&space()), Some(KeyAction::ToggleSelect));
+        assert_eq!(get_key_action(&KeyInput { ctrl: true, ..key_input(Key::Character("A".to_string())) }), Some(KeyAction::SelectAll));
+        assert_eq!(get_key_action(&KeyInput { ctrl: true, select_many: false, ..key_input(Key::Character("a".to_string())) }), None);
+    }
+}
@@ -0,0 +1,98 @@
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::rc::Rc;
+
+use crate::components::table_view::get_column_option_map::{RenderMap, RenderOptions};
+
+pub use dioxus_ui_macros::TableRow;
+
+type ValueMapperFn<T> = Rc<dyn Fn(&dyn Any, &RenderOptions<T>) -> String>;
+type AscSorterFn = Rc<dyn Fn(&dyn Any, &dyn Any) -> Ordering>;
+
+/// Row type with typed column definitions
+///
+/// Derive it with `#[derive(TableRow)]` to get a column per field.
+pub trait TableRow: Sized + 'static {
+    fn render_map() -> RenderMap<Self>;
+}
+
+/// Value mapper showing a field by `Display`
+pub fn display_mapper<T: 'static, V: Display + ?Sized + 'static>(accessor: fn(&T) -> &V) -> ValueMapperFn<T> {
+    Rc::new(move |raw, _options| {
+        raw.downcast_ref::<T>()
+            .map(|row| accessor(row).to_string())
+            .unwrap_or_default()
+    })
+}
+
+/// Value mapper showing an optional field by `Display` (empty for `None`)
+pub fn option_display_mapper<T: 'static, V: Display + 'static>(accessor: fn(&T) -> &Option<V>) -> ValueMapperFn<T> {
+    Rc::new(move |raw, _options| {
+        raw.downcast_ref::<T>()
+            .and_then(|row| accessor(row).as_ref())
+            .map(|value| value.to_string())
+            .unwrap_or_default()
+    })
+}
+
+/// Ascending sorter comparing a field by `PartialOrd` (incomparable values are equal)
+pub fn ord_sorter<T: 'static, V: PartialOrd + ?Sized + 'static>(accessor: fn(&T) -> &V) -> AscSorterFn {
+    Rc::new(move |prev, next| {
+        match (prev.downcast_ref::<T>(), next.downcast_ref::<T>()) {
+            (Some(prev), Some(next)) => accessor(prev).partial_cmp(accessor(next)).unwrap_or(Ordering::Equal),
+            _ => Ordering::Equal,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::table_view::get_column_option_map::{get_cell_text, get_column_option_map};
+
+    #[derive(Clone, Debug, PartialEq, TableRow)]
+    struct Product {
+        #[table(label = "Code", row_header)]
+        code: String,
+        price: u32,
+        rating: Option<f64>,
+        #[table(skip)]
+        #[allow(dead_code)]
+        memo: String,
+    }
+
+    fn product(code: &str, price: u32, rating: Option<f64>) -> Product {
+        Product { code: code.to_string(), price, rating, memo: String::new() }
+    }
+
+    #[test]
+    fn test_derive_table_row() {
+        let column_option_map = get_column_option_map(&Product::render_map());
+        let mut keys: Vec<&String> = column_option_map.keys().collect();
+        keys.sort();
+
+        assert_eq!(keys, vec!["code", "price", "rating"]);
+        assert_eq!(column_option_map["code"].label, "Code");
+        assert!(column_option_map["code"].is_row_header);
+        assert_eq!(column_option_map["price"].label, "price");
+        assert_eq!(column_option_map["price"].align, "right");
+        assert_eq!(column_option_map["code"].align, "left");
+
+        let row = product("a-1", 900, None);
+        assert_eq!(get_cell_text(&column_option_map["price"], &row, "0", 0), "900");
+        assert_eq!(get_cell_text(&column_option_map["rating"], &row, "0", 0), "");
+        assert_eq!(column_option_map["price"].number_mapper.as_ref().and_then(|mapper| mapper(&row)), Some(900.0));
+    }
+
+    #[test]
+    fn test_derive_table_row_sorts_numbers() {
+        let column_option_map = get_column_option_map(&Product::render_map());
+        let sorter = &column_option_map["price"].asc_sorter;
+        let (cheap, expensive) = (product("a", 900, Some(4.5)), product("b", 1000, None));
+
+        // "1000" < "900" as text
+        assert_eq!(sorter(&cheap as &dyn Any, &expensive as &dyn Any), Ordering::Less);
+        assert_eq!((column_option_map["rating"].asc_sorter)(&expensive as &dyn Any, &cheap as &dyn Any), Ordering::Less);
+    }
+}
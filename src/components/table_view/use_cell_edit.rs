@@ -0,0 +1,164 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::components::table_view::cell_editor::EditorKind;
+
+/// Committed value of an edited cell
+#[derive(Clone, Debug, PartialEq)]
+pub struct CellEditEvent {
+    pub row_id: String,
+    pub column_key: String,
+    pub value: String,
+}
+
+/// Applies an edit, or gives the error to show in the editor
+pub type OnCellEditFn = Callback<CellEditEvent, Result<(), String>>;
+
+/// Cell being edited
+#[derive(Clone, PartialEq)]
+pub struct CellEdit {
+    pub row_id: String,
+    pub column_key: String,
+    pub kind: EditorKind,
+    /// Edited text
+    pub value: String,
+    /// Why the last commit failed
+    pub error: Option<String>,
+}
+
+impl CellEdit {
+    pub fn new(row_id: String, column_key: String, kind: EditorKind, value: String) -> Self {
+        Self { row_id, column_key, kind, value, error: None }
+    }
+
+    pub fn is_editing(&self, row_id: &str, column_key: &str) -> bool {
+        self.row_id == row_id && self.column_key == column_key
+    }
+}
+
+#[derive(Clone)]
+pub struct UseCellEditResult {
+    pub editing: Option<CellEdit>,
+    pub start: Rc<RefCell<dyn FnMut(CellEdit)>>,
+    pub set_value: Rc<RefCell<dyn FnMut(String)>>,
+    /// Commit the edited text, returning whether the editor closed
+    pub commit: Rc<RefCell<dyn FnMut() -> bool>>,
+    pub cancel: Rc<RefCell<dyn FnMut()>>,
+}
+
+/// Hook for editing one cell at a time
+///
+/// A commit parses the text by the editor kind and passes it to `on_cell_edit`.
+/// Errors of either keep the editor open with the error shown.
+pub fn use_cell_edit(on_cell_edit: Option<OnCellEditFn>) -> UseCellEditResult {
+    let editing = use_signal(|| None::<CellEdit>);
+
+    let start = {
+        let mut editing = editing;
+        Rc::new(RefCell::new(move |edit: CellEdit| {
+            editing.set(Some(edit));
+        }))
+    };
+
+    let set_value = {
+        let mut editing = editing;
+        Rc::new(RefCell::new(move |value: String| {
+            editing.with_mut(|edit| {
+                if let Some(edit) = edit {
+                    edit.value = value;
+                    edit.error = None;
+                }
+            });
+        }))
+    };
+
+    let commit = {
+        let mut editing = editing;
+        Rc::new(RefCell::new(move || -> bool {
+            let Some(edit) = editing.peek().clone() else {
+                return true;
+            };
+            let result = edit.kind.parse(&edit.value).and_then(|value| match on_cell_edit {
+                Some(on_cell_edit) => on_cell_edit.call(CellEditEvent {
+                    row_id: edit.row_id.clone(),
+                    column_key: edit.column_key.clone(),
+                    value,
+                }),
+                None => Ok(()),
+            });
+            match result {
+                Ok(()) => {
+                    editing.set(None);
+                    true
+                }
+                Err(error) => {
+                    editing.set(Some(CellEdit { error: Some(error), ..edit }));
+                    false
+                }
+            }
+        }))
+    };
+
+    let cancel = {
+        let mut editing = editing;
+        Rc::new(RefCell::new(move || {
+            editing.set(None);
+        }))
+    };
+
+    let current_editing = editing.read().clone();
+
+    UseCellEditResult {
+        editing: current_editing,
+        start,
+        set_value,
+        commit,
+        cancel,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_use_cell_edit() {
+        let mut dom = VirtualDom::new(|| {
+            let committed = use_hook(|| Rc::new(RefCell::new(Vec::<CellEditEvent>::new())));
+            let cell_edit = use_cell_edit(Some(Callback::new({
+                let committed = committed.clone();
+                move |event: CellEditEvent| {
+                    if event.value == "0" {
+                        return Err("Must not be zero".to_string());
+                    }
+                    committed.borrow_mut().push(event);
+                    Ok(())
+                }
+            })));
+
+            use_hook(|| {
+                cell_edit.start.borrow_mut()(CellEdit::new("1".to_string(), "value".to_string(), EditorKind::Number, "10".to_string()));
+
+                // Text that is not a number never reaches the callback
+                cell_edit.set_value.borrow_mut()("abc".to_string());
+                assert!(!cell_edit.commit.borrow_mut()());
+                cell_edit.set_value.borrow_mut()("0".to_string());
+                assert!(!cell_edit.commit.borrow_mut()());
+                assert!(committed.borrow().is_empty());
+
+                cell_edit.set_value.borrow_mut()("12".to_string());
+                assert!(cell_edit.commit.borrow_mut()());
+                assert_eq!(*committed.borrow(), vec![CellEditEvent {
+                    row_id: "1".to_string(),
+                    column_key: "value".to_string(),
+                    value: "12".to_string(),
+                }]);
+            });
+
+            rsx! { div {} }
+        });
+
+        dom.rebuild_to_vec();
+    }
+}
@@ -0,0 +1,150 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::components::table_view::get_column_option_map::ColumnOptionMap;
+use crate::types::setter::{SetStateAction, SetterUtils};
+
+type SetOrderFn = Rc<RefCell<dyn FnMut(SetStateAction<Vec<String>>)>>;
+
+/// Complete an order with the keys of the columns
+///
+/// Unknown keys are dropped, and keys missing from the order follow in `keys` order.
+pub fn normalize_column_order(order: &[String], keys: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = order.iter().filter(|key| keys.contains(key)).cloned().collect();
+    normalized.extend(keys.iter().filter(|key| !order.contains(key)).cloned());
+    normalized
+}
+
+/// Move a column to `to_index` keeping the row header grouping
+///
+/// Row header columns only move within their run of row headers, and other columns are
+/// never inserted inside such a run (they go after it), so that the bundles computed by
+/// `get_column_bundles_par_row_header` keep their row headers.
+pub fn move_column_key(order: &[String], key: &str, to_index: usize, is_row_header: impl Fn(&str) -> bool) -> Vec<String> {
+    let Some(from) = order.iter().position(|it| it == key) else {
+        return order.to_vec();
+    };
+    let mut rest = order.to_vec();
+    let moved = rest.remove(from);
+    let mut index = to_index.min(rest.len());
+
+    if is_row_header(key) {
+        let run_start = (0..from).rev().take_while(|i| is_row_header(&order[*i])).last().unwrap_or(from);
+        let run_end = (from + 1..order.len()).take_while(|i| is_row_header(&order[*i])).last().map_or(from + 1, |i| i + 1);
+        index = index.clamp(run_start, run_end - 1);
+    } else {
+        while index > 0 && index < rest.len() && is_row_header(&rest[index - 1]) && is_row_header(&rest[index]) {
+            index += 1;
+        }
+    }
+
+    rest.insert(index, moved);
+    rest
+}
+
+#[derive(Clone)]
+pub struct UseColumnOrderResult {
+    pub init: Rc<RefCell<dyn FnMut()>>,
+    /// Keys of every column in display order
+    pub order: Vec<String>,
+    pub set_order: SetOrderFn,
+    /// Move a column to an index of `order` (see `move_column_key`)
+    pub move_column: Rc<RefCell<dyn FnMut(String, usize)>>,
+}
+
+/// Hook for the display order of the columns
+///
+/// `init` may leave out columns; they follow in key order.
+pub fn use_column_order<T>(
+    init: impl Fn() -> Vec<String> + Clone + 'static,
+    column_option_map: &ColumnOptionMap<T>,
+) -> UseColumnOrderResult {
+    let order = use_signal(&init);
+
+    // Same order as `get_column_options`
+    let mut keys: Vec<String> = column_option_map.keys().cloned().collect();
+    keys.sort();
+    let is_row_header_map: HashMap<String, bool> = column_option_map.iter()
+        .map(|(key, column)| (key.clone(), column.is_row_header))
+        .collect();
+
+    let set_order = {
+        let mut order = order;
+        Rc::new(RefCell::new(move |set_state_action: SetStateAction<Vec<String>>| {
+            let prev = order.read().clone();
+            order.set(SetterUtils::to_value(set_state_action, prev));
+        }))
+    };
+
+    let move_column = {
+        let mut order = order;
+        let keys = keys.clone();
+        Rc::new(RefCell::new(move |key: String, to_index: usize| {
+            let normalized = normalize_column_order(&order.peek(), &keys);
+            let moved = move_column_key(&normalized, &key, to_index, |key| {
+                is_row_header_map.get(key).copied().unwrap_or(false)
+            });
+            if moved != normalized {
+                order.set(moved);
+            }
+        }))
+    };
+
+    let init_fn = {
+        let mut order = order;
+        Rc::new(RefCell::new(move || {
+            order.set(init());
+        }))
+    };
+
+    let current_order = normalize_column_order(&order.read(), &keys);
+
+    UseColumnOrderResult {
+        init: init_fn,
+        order: current_order,
+        set_order,
+        move_column,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_keys(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    fn is_row_header(key: &str) -> bool {
+        key.starts_with('h')
+    }
+
+    #[test]
+    fn test_normalize_column_order() {
+        let order = normalize_column_order(&to_keys(&["c", "x", "a"]), &to_keys(&["a", "b", "c"]));
+
+        assert_eq!(order, to_keys(&["c", "a", "b"]));
+    }
+
+    #[test]
+    fn test_move_column_key() {
+        let order = to_keys(&["h1", "h2", "c1", "c2", "h3", "c3"]);
+
+        assert_eq!(move_column_key(&order, "c2", 2, is_row_header), to_keys(&["h1", "h2", "c2", "c1", "h3", "c3"]));
+        assert_eq!(move_column_key(&order, "c1", 5, is_row_header), to_keys(&["h1", "h2", "c2", "h3", "c3", "c1"]));
+        assert_eq!(move_column_key(&order, "x", 0, is_row_header), order);
+    }
+
+    #[test]
+    fn test_move_column_key_keeps_row_header_runs() {
+        let order = to_keys(&["h1", "h2", "c1", "c2", "h3", "c3"]);
+
+        // Row headers stay within their run
+        assert_eq!(move_column_key(&order, "h1", 4, is_row_header), to_keys(&["h2", "h1", "c1", "c2", "h3", "c3"]));
+        assert_eq!(move_column_key(&order, "h3", 0, is_row_header), order);
+        // Other columns are not inserted between row headers
+        assert_eq!(move_column_key(&order, "c3", 1, is_row_header), to_keys(&["h1", "h2", "c3", "c1", "c2", "h3"]));
+    }
+}
@@ -0,0 +1,192 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::components::table_view::get_column_option_map::ColumnOption;
+use crate::components::table_view::pinned_columns::Pin;
+
+/// Options of the column-axis virtualization
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnVirtualizationOptions {
+    /// Width of columns whose width is not given in pixels
+    /// Default is `150.0`
+    pub default_column_width_px: Option<f64>,
+    /// Number of columns rendered beyond each side of the view
+    /// Default is `2`
+    pub over_scan: Option<usize>,
+}
+
+/// Parse a column width in pixels (`"120px"` or `"120"`)
+pub fn parse_px_width(width: &str) -> Option<f64> {
+    let width = width.trim();
+    width.strip_suffix("px").unwrap_or(width).trim().parse().ok()
+}
+
+/// Range of the content columns intersecting the view, widened by `over_scan`
+///
+/// `columns` are the visible columns in grid order as (is_fixed, width), where row header
+/// and pinned columns are fixed. The range indexes the other columns only.
+pub fn get_column_window(columns: &[(bool, f64)], scroll_left: f64, view_width: f64, over_scan: usize) -> Range<usize> {
+    let mut left_px = 0.0;
+    let mut contents: Vec<(f64, f64)> = Vec::new();
+    for (is_fixed, width) in columns {
+        if !is_fixed {
+            contents.push((left_px, *width));
+        }
+        left_px += width;
+    }
+
+    let view_end = scroll_left + view_width;
+    let start = contents.partition_point(|(left, width)| left + width <= scroll_left);
+    let end = contents.partition_point(|(left, _)| *left < view_end);
+    start.saturating_sub(over_scan)..(end + over_scan).min(contents.len())
+}
+
+#[derive(Clone)]
+pub struct UseColumnVirtualizationResult {
+    pub is_enabled: bool,
+    /// Widths of the visible columns in pixels (empty when disabled)
+    pub width_px_map: HashMap<String, f64>,
+    /// Rendered content columns, indexed over the visible unpinned content columns in grid order
+    pub window: Range<usize>,
+    pub set_scroll_left: Rc<RefCell<dyn FnMut(f64)>>,
+    pub set_view_width: Rc<RefCell<dyn FnMut(f64)>>,
+}
+
+impl UseColumnVirtualizationResult {
+    /// Pin of a column, row headers being pinned to the left while enabled
+    pub fn get_pin<T>(&self, column: &ColumnOption<T>) -> Option<Pin> {
+        column.pinned.or((self.is_enabled && column.is_row_header).then_some(Pin::Left))
+    }
+
+    /// Split the content columns of a bundle into (skipped before, rendered, skipped after)
+    ///
+    /// `content_start` is the index of the first visible column among the unpinned content columns.
+    /// Hidden columns are left out while enabled since they take no grid column.
+    pub fn split<'a, C>(&self, columns: &'a [C], content_start: usize, is_hidden: impl Fn(&C) -> bool) -> (usize, Vec<&'a C>, usize) {
+        if !self.is_enabled {
+            return (0, columns.iter().collect(), 0);
+        }
+        let visible: Vec<&C> = columns.iter().filter(|column| !is_hidden(column)).collect();
+        let local_start = self.window.start.saturating_sub(content_start).min(visible.len());
+        let local_end = self.window.end.saturating_sub(content_start).clamp(local_start, visible.len());
+        (local_start, visible[local_start..local_end].to_vec(), visible.len() - local_end)
+    }
+}
+
+/// Hook rendering only the content columns around the horizontal view
+///
+/// `columns` are in display order. Widths come from `use_column_widths` (`default_column_width_px`
+/// unless given in pixels), while row header and pinned columns are always rendered
+/// (row headers being pinned to the left, see `get_pin`). `None` disables it.
+pub fn use_column_virtualization<T: Clone>(
+    options: Option<ColumnVirtualizationOptions>,
+    columns: &[ColumnOption<T>],
+    column_widths: &HashMap<String, Option<String>>,
+) -> UseColumnVirtualizationResult {
+    let scroll_left = use_signal(|| 0.0_f64);
+    let view_width_px = use_signal(|| None::<f64>);
+
+    let set_scroll_left = {
+        let mut scroll_left = scroll_left;
+        Rc::new(RefCell::new(move |px: f64| {
+            if *scroll_left.peek() != px {
+                scroll_left.set(px);
+            }
+        }))
+    };
+
+    let set_view_width = {
+        let mut view_width_px = view_width_px;
+        Rc::new(RefCell::new(move |px: f64| {
+            if *view_width_px.peek() != Some(px) {
+                view_width_px.set(Some(px));
+            }
+        }))
+    };
+
+    let Some(options) = options else {
+        return UseColumnVirtualizationResult {
+            is_enabled: false,
+            width_px_map: HashMap::new(),
+            window: 0..usize::MAX,
+            set_scroll_left,
+            set_view_width,
+        };
+    };
+
+    let default_width = options.default_column_width_px.unwrap_or(150.0);
+    let columns: Vec<(String, bool, f64)> = columns
+        .iter()
+        .filter(|column| !column.is_hidden)
+        .map(|column| {
+            let width = column_widths.get(&column.key)
+                .cloned()
+                .flatten()
+                .unwrap_or_else(|| column.init_column_width.clone());
+            let is_fixed = column.is_row_header || column.pinned.is_some();
+            (column.key.clone(), is_fixed, parse_px_width(&width).unwrap_or(default_width))
+        })
+        .collect();
+
+    let window = get_column_window(
+        &columns.iter().map(|(_, is_fixed, width)| (*is_fixed, *width)).collect::<Vec<_>>(),
+        *scroll_left.read(),
+        // Assume ten columns until the container has been measured
+        view_width_px.read().unwrap_or(default_width * 10.0),
+        options.over_scan.unwrap_or(2),
+    );
+
+    UseColumnVirtualizationResult {
+        is_enabled: true,
+        width_px_map: columns.into_iter().map(|(key, _, width)| (key, width)).collect(),
+        window,
+        set_scroll_left,
+        set_view_width,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_px_width() {
+        assert_eq!(parse_px_width("120px"), Some(120.0));
+        assert_eq!(parse_px_width(" 80 "), Some(80.0));
+        assert_eq!(parse_px_width("minmax(max-content, 1fr)"), None);
+    }
+
+    #[test]
+    fn test_get_column_window() {
+        // Row header of 100px, then ten content columns of 50px
+        let columns: Vec<(bool, f64)> = std::iter::once((true, 100.0))
+            .chain(std::iter::repeat_n((false, 50.0), 10))
+            .collect();
+
+        assert_eq!(get_column_window(&columns, 0.0, 200.0, 0), 0..2);
+        assert_eq!(get_column_window(&columns, 225.0, 100.0, 0), 2..5);
+        assert_eq!(get_column_window(&columns, 225.0, 100.0, 1), 1..6);
+        assert_eq!(get_column_window(&columns, 1000.0, 100.0, 2), 8..10);
+    }
+
+    #[test]
+    fn test_split() {
+        let mut column_virtualization = UseColumnVirtualizationResult {
+            is_enabled: true,
+            width_px_map: HashMap::new(),
+            window: 3..5,
+            set_scroll_left: Rc::new(RefCell::new(|_| {})),
+            set_view_width: Rc::new(RefCell::new(|_| {})),
+        };
+        let columns = ["a", "hidden", "b", "c", "d"];
+
+        let (before, rendered, after) = column_virtualization.split(&columns, 2, |column| *column == "hidden");
+        assert_eq!((before, rendered, after), (1, vec![&"b", &"c"], 1));
+
+        column_virtualization.is_enabled = false;
+        assert_eq!(column_virtualization.split(&columns, 2, |_| false).1.len(), 5);
+    }
+}
@@ -0,0 +1,181 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use crate::components::table_view::get_column_option_map::ColumnOptionMap;
+use crate::types::setter::{SetStateAction, SetterUtils};
+
+type SetHiddenKeysFn = Rc<RefCell<dyn FnMut(SetStateAction<HashSet<String>>)>>;
+type SetByKeyFn = Rc<RefCell<dyn FnMut(String)>>;
+
+/// Keys of the columns hidden by their options (`is_hidden`)
+pub fn get_default_hidden_keys<T>(column_option_map: &ColumnOptionMap<T>) -> HashSet<String> {
+    column_option_map
+        .iter()
+        .filter(|(_, column)| column.is_hidden)
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
+/// Column options with `is_hidden` taken from `hidden_keys`
+pub fn apply_column_visibility<T: Clone>(column_option_map: &ColumnOptionMap<T>, hidden_keys: &HashSet<String>) -> ColumnOptionMap<T> {
+    column_option_map
+        .iter()
+        .map(|(key, column)| {
+            let mut column = column.clone();
+            column.is_hidden = hidden_keys.contains(key);
+            (key.clone(), column)
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct UseColumnVisibilityResult {
+    /// Keys of the hidden columns
+    pub hidden_keys: HashSet<String>,
+    pub set_hidden_keys: SetHiddenKeysFn,
+    pub hide: SetByKeyFn,
+    pub show: SetByKeyFn,
+    pub toggle: SetByKeyFn,
+    /// Back to the `is_hidden` of the column options
+    pub reset: Rc<RefCell<dyn FnMut()>>,
+}
+
+impl UseColumnVisibilityResult {
+    pub fn is_hidden(&self, key: &str) -> bool {
+        self.hidden_keys.contains(key)
+    }
+}
+
+/// Hook for showing and hiding columns at runtime
+///
+/// Starts from the `is_hidden` of the column options.
+pub fn use_column_visibility<T>(column_option_map: &ColumnOptionMap<T>) -> UseColumnVisibilityResult {
+    let default_hidden_keys = get_default_hidden_keys(column_option_map);
+    let hidden_keys = use_signal({
+        let default_hidden_keys = default_hidden_keys.clone();
+        move || default_hidden_keys
+    });
+
+    let set_hidden_keys = {
+        let mut hidden_keys = hidden_keys;
+        Rc::new(RefCell::new(move |set_state_action: SetStateAction<HashSet<String>>| {
+            let prev = hidden_keys.peek().clone();
+            let next = SetterUtils::to_value(set_state_action, prev.clone());
+            if next != prev {
+                hidden_keys.set(next);
+            }
+        }))
+    };
+
+    let set_by_key = |update: fn(&mut HashSet<String>, String)| -> SetByKeyFn {
+        let set_hidden_keys = set_hidden_keys.clone();
+        Rc::new(RefCell::new(move |key: String| {
+            set_hidden_keys.borrow_mut()(SetStateAction::Function(Rc::new(move |mut prev: HashSet<String>| {
+                update(&mut prev, key.clone());
+                prev
+            })));
+        }))
+    };
+
+    let hide = set_by_key(|keys, key| {
+        keys.insert(key);
+    });
+    let show = set_by_key(|keys, key| {
+        keys.remove(&key);
+    });
+    let toggle = set_by_key(|keys, key| {
+        if !keys.remove(&key) {
+            keys.insert(key);
+        }
+    });
+
+    let reset = {
+        let set_hidden_keys = set_hidden_keys.clone();
+        Rc::new(RefCell::new(move || {
+            set_hidden_keys.borrow_mut()(SetStateAction::Value(default_hidden_keys.clone()));
+        }))
+    };
+
+    let current_hidden_keys = hidden_keys.read().clone();
+
+    UseColumnVisibilityResult {
+        hidden_keys: current_hidden_keys,
+        set_hidden_keys,
+        hide,
+        show,
+        toggle,
+        reset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::render_until_idle;
+    use crate::components::table_view::get_column_option_map::{get_column_option_map, ColumnOptionArgs, RenderMap, RenderMapValue};
+
+    fn create_column_option_map() -> ColumnOptionMap<()> {
+        let mut render_map: RenderMap<()> = RenderMap::new();
+        render_map.insert("a".to_string(), RenderMapValue::Label("A".to_string()));
+        render_map.insert("b".to_string(), RenderMapValue::Options(ColumnOptionArgs {
+            label: "B".to_string(),
+            is_hidden: Some(true),
+            ..Default::default()
+        }));
+        get_column_option_map(&render_map)
+    }
+
+    #[test]
+    fn test_apply_column_visibility() {
+        let column_option_map = create_column_option_map();
+        assert_eq!(get_default_hidden_keys(&column_option_map), HashSet::from(["b".to_string()]));
+
+        let applied = apply_column_visibility(&column_option_map, &HashSet::from(["a".to_string()]));
+        assert!(applied["a"].is_hidden);
+        assert!(!applied["b"].is_hidden);
+    }
+
+    #[tokio::test]
+    async fn test_use_column_visibility() {
+        type Latest = Rc<RefCell<Option<UseColumnVisibilityResult>>>;
+
+        fn app(latest: Latest) -> Element {
+            let column_visibility = use_column_visibility(&create_column_option_map());
+            *latest.borrow_mut() = Some(column_visibility);
+
+            rsx! { div {} }
+        }
+
+        let latest: Latest = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(app, latest.clone());
+        dom.rebuild_in_place();
+        let column_visibility = || latest.borrow().clone().unwrap();
+        let hidden_keys = |keys: &[&str]| keys.iter().map(|key| key.to_string()).collect::<HashSet<_>>();
+
+        assert!(!column_visibility().is_hidden("a"));
+        assert!(column_visibility().is_hidden("b"));
+
+        dom.in_runtime(|| column_visibility().hide.borrow_mut()("a".to_string()));
+        render_until_idle(&mut dom).await;
+        assert_eq!(column_visibility().hidden_keys, hidden_keys(&["a", "b"]));
+
+        dom.in_runtime(|| column_visibility().show.borrow_mut()("b".to_string()));
+        render_until_idle(&mut dom).await;
+        assert_eq!(column_visibility().hidden_keys, hidden_keys(&["a"]));
+
+        dom.in_runtime(|| {
+            column_visibility().toggle.borrow_mut()("a".to_string());
+            column_visibility().toggle.borrow_mut()("b".to_string());
+        });
+        render_until_idle(&mut dom).await;
+        assert_eq!(column_visibility().hidden_keys, hidden_keys(&["b"]));
+
+        dom.in_runtime(|| column_visibility().hide.borrow_mut()("a".to_string()));
+        render_until_idle(&mut dom).await;
+        dom.in_runtime(|| column_visibility().reset.borrow_mut()());
+        render_until_idle(&mut dom).await;
+        assert_eq!(column_visibility().hidden_keys, hidden_keys(&["b"]));
+    }
+}
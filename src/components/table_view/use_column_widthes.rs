@@ -11,6 +11,7 @@ type SetFn = Rc<std::cell::RefCell<dyn FnMut(HashMap<String, Option<String>>)>>;
 type SetByKeyFn = Rc<std::cell::RefCell<dyn FnMut(String, Option<String>)>>;
 
 /// Result type for column widths hook
+#[derive(Clone)]
 pub struct UseColumnWidthsResult {
     /// Initialize column widths to default values
     pub init: InitFn,
@@ -121,6 +122,7 @@ pub fn use_column_widths<T>(column_option_map: ColumnOptionMap<T>) -> UseColumnW
 mod tests {
     use super::*;
     use crate::components::table_view::get_column_option_map::ColumnOption;
+    use crate::components::table_view::get_column_totals::Aggregate;
     use crate::components::table_view::use_sort::SortOrder;
     use std::rc::Rc;
 
@@ -137,8 +139,15 @@ mod tests {
             sort_order_is_changeable: true,
             is_hidden: false,
             init_column_width: "200px".to_string(),
+            min_width_px: 20.0,
+            max_width_px: None,
+            pinned: None,
             align: "left".to_string(),
             total: false,
+            aggregate: Aggregate::Sum,
+            number_mapper: None,
+            filter: None,
+            editor: None,
         });
         
         map.insert("col2".to_string(), ColumnOption {
@@ -151,8 +160,15 @@ mod tests {
             sort_order_is_changeable: true,
             is_hidden: false,
             init_column_width: "150px".to_string(),
+            min_width_px: 20.0,
+            max_width_px: None,
+            pinned: None,
             align: "right".to_string(),
             total: false,
+            aggregate: Aggregate::Sum,
+            number_mapper: None,
+            filter: None,
+            editor: None,
         });
         
         map
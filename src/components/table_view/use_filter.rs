@@ -0,0 +1,272 @@
+use dioxus::prelude::*;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::types::setter::{SetStateAction, SetterUtils};
+
+// Type aliases for complex function types
+/// Custom filter predicate receiving the row and the filter input
+pub type CustomFilterFn = Rc<dyn Fn(&dyn Any, &str) -> bool>;
+type SetFilterMapFn<K> = Rc<RefCell<dyn FnMut(SetStateAction<HashMap<K, FilterValue>>)>>;
+pub type SetFilterFn<K> = Rc<dyn Fn(K) -> Rc<RefCell<dyn FnMut(Option<FilterValue>)>>>;
+
+/// Kind of filter a column accepts
+///
+/// Decides which editor the header renders and how the cell is matched.
+#[derive(Clone)]
+pub enum FilterKind {
+    /// Text contains / equals
+    Text,
+    /// Numeric range over the cell text parsed as a number
+    NumberRange,
+    /// Date range over ISO 8601 cell text (`YYYY-MM-DD...`)
+    DateRange,
+    /// Membership in a set of distinct cell texts
+    Set,
+    /// Custom predicate
+    Custom(CustomFilterFn),
+}
+
+impl PartialEq for FilterKind {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (FilterKind::Custom(prev), FilterKind::Custom(next)) => Rc::ptr_eq(prev, next),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+/// Filter value set on a column
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    /// Cell text contains the query (case-insensitive)
+    Contains(String),
+    /// Cell text equals the query
+    Equals(String),
+    /// Cell number is within the range (inclusive)
+    NumberRange { min: Option<f64>, max: Option<f64> },
+    /// Cell date is within the range (inclusive)
+    DateRange { from: Option<String>, to: Option<String> },
+    /// Cell text is one of the values
+    OneOf(Vec<String>),
+    /// Input passed to the column's custom predicate
+    Custom(String),
+}
+
+impl FilterValue {
+    /// Whether the cell matches this filter
+    ///
+    /// `row` and `custom` are only used by `FilterValue::Custom`,
+    /// which matches everything without a custom predicate.
+    pub fn is_match(&self, cell_text: &str, row: &dyn Any, custom: Option<&CustomFilterFn>) -> bool {
+        match self {
+            FilterValue::Contains(query) => cell_text.to_lowercase().contains(&query.to_lowercase()),
+            FilterValue::Equals(query) => cell_text == query,
+            FilterValue::NumberRange { min, max } => match parse_number(cell_text) {
+                Some(value) => min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max),
+                None => false,
+            },
+            FilterValue::DateRange { from, to } => {
+                let date = cell_text.trim();
+                if date.is_empty() {
+                    return false;
+                }
+                // Compare only the precision of the bound so that `to` includes the whole day
+                from.as_ref().is_none_or(|from| date >= from.as_str())
+                    && to.as_ref().is_none_or(|to| date.get(..to.len()).unwrap_or(date) <= to.as_str())
+            }
+            FilterValue::OneOf(values) => values.iter().any(|value| value == cell_text),
+            FilterValue::Custom(input) => custom.is_none_or(|predicate| predicate(row, input)),
+        }
+    }
+}
+
+/// Parse cell text as a number, ignoring digit separators
+pub fn parse_number(text: &str) -> Option<f64> {
+    text.trim()
+        .chars()
+        .filter(|ch| *ch != ',' && *ch != '_')
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+/// Get the distinct values of a column in ascending order (for set filters)
+pub fn get_distinct_values(texts: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut values: Vec<String> = texts.into_iter().collect();
+    values.sort();
+    values.dedup();
+    values
+}
+
+/// Keep rows matching every column filter
+///
+/// `get_cell_text` maps (column key, row, data index) to the text of the cell.
+pub fn get_filtered<T: 'static, K: Hash + Eq>(
+    data: Vec<T>,
+    filter_map: &HashMap<K, FilterValue>,
+    custom_filter_map: &HashMap<K, CustomFilterFn>,
+    get_cell_text: impl Fn(&K, &T, usize) -> String,
+) -> Vec<T> {
+    if filter_map.is_empty() {
+        return data;
+    }
+
+    data.into_iter()
+        .enumerate()
+        .filter(|(index, row)| {
+            filter_map.iter().all(|(key, filter)| {
+                filter.is_match(&get_cell_text(key, row, *index), row as &dyn Any, custom_filter_map.get(key))
+            })
+        })
+        .map(|(_, row)| row)
+        .collect()
+}
+
+#[derive(Clone)]
+pub struct UseFilterResult<K>
+where
+    K: Clone + Hash + Eq + 'static,
+{
+    pub init: Rc<RefCell<dyn FnMut()>>,
+    pub filter_map: HashMap<K, FilterValue>,
+    pub set_filter_map: SetFilterMapFn<K>,
+    /// Set (`Some`) or clear (`None`) the filter of a column
+    pub set_filter: SetFilterFn<K>,
+    pub clear_all: Rc<RefCell<dyn FnMut()>>,
+}
+
+pub fn use_filter<K>(
+    init: impl Fn() -> HashMap<K, FilterValue> + Clone + 'static,
+) -> UseFilterResult<K>
+where
+    K: Clone + Hash + Eq + 'static,
+{
+    let filter_map = use_signal(&init);
+
+    let set_filter_map = {
+        let mut filter_map = filter_map;
+        Rc::new(RefCell::new(move |set_state_action: SetStateAction<HashMap<K, FilterValue>>| {
+            let prev = filter_map.read().clone();
+            filter_map.set(SetterUtils::to_value(set_state_action, prev));
+        }))
+    };
+
+    let set_filter = {
+        Rc::new(move |key: K| -> Rc<RefCell<dyn FnMut(Option<FilterValue>)>> {
+            let mut filter_map = filter_map;
+            Rc::new(RefCell::new(move |filter: Option<FilterValue>| {
+                filter_map.with_mut(|prev| match filter {
+                    Some(filter) => {
+                        prev.insert(key.clone(), filter);
+                    }
+                    None => {
+                        prev.remove(&key);
+                    }
+                });
+            }))
+        })
+    };
+
+    let init_fn = {
+        let mut filter_map = filter_map;
+        let init = init.clone();
+        Rc::new(RefCell::new(move || {
+            filter_map.set(init());
+        }))
+    };
+
+    let clear_all = {
+        let mut filter_map = filter_map;
+        Rc::new(RefCell::new(move || {
+            filter_map.set(HashMap::new());
+        }))
+    };
+
+    let current_filter_map = filter_map.read().clone();
+
+    UseFilterResult {
+        init: init_fn,
+        filter_map: current_filter_map,
+        set_filter_map,
+        set_filter,
+        clear_all,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_filter() {
+        let row: &dyn Any = &();
+
+        assert!(FilterValue::Contains("LI".to_string()).is_match("Alice", row, None));
+        assert!(!FilterValue::Contains("bob".to_string()).is_match("Alice", row, None));
+        assert!(FilterValue::Equals("Alice".to_string()).is_match("Alice", row, None));
+        assert!(!FilterValue::Equals("alice".to_string()).is_match("Alice", row, None));
+    }
+
+    #[test]
+    fn test_range_filters() {
+        let row: &dyn Any = &();
+        let number_range = FilterValue::NumberRange { min: Some(10.0), max: Some(1000.0) };
+
+        assert!(number_range.is_match("1,000", row, None));
+        assert!(!number_range.is_match("9.5", row, None));
+        assert!(!number_range.is_match("n/a", row, None));
+
+        let date_range = FilterValue::DateRange {
+            from: Some("2024-01-01".to_string()),
+            to: Some("2024-01-31".to_string()),
+        };
+
+        assert!(date_range.is_match("2024-01-31T23:59:59", row, None));
+        assert!(!date_range.is_match("2023-12-31", row, None));
+        assert!(!date_range.is_match("", row, None));
+    }
+
+    #[test]
+    fn test_set_and_custom_filters() {
+        let row: &dyn Any = &42i32;
+        let one_of = FilterValue::OneOf(vec!["a".to_string(), "b".to_string()]);
+
+        assert!(one_of.is_match("b", row, None));
+        assert!(!one_of.is_match("c", row, None));
+
+        let is_greater: CustomFilterFn = Rc::new(|row, input| {
+            let threshold: i32 = input.parse().unwrap_or(0);
+            row.downcast_ref::<i32>().is_some_and(|value| *value > threshold)
+        });
+
+        assert!(FilterValue::Custom("40".to_string()).is_match("", row, Some(&is_greater)));
+        assert!(!FilterValue::Custom("50".to_string()).is_match("", row, Some(&is_greater)));
+    }
+
+    #[test]
+    fn test_get_filtered() {
+        let data = vec![("Alice", 30), ("Bob", 20), ("Carol", 40)];
+        let filter_map = HashMap::from([
+            ("age", FilterValue::NumberRange { min: Some(25.0), max: None }),
+            ("name", FilterValue::Contains("o".to_string())),
+        ]);
+
+        let filtered = get_filtered(data, &filter_map, &HashMap::new(), |key, row, _index| match *key {
+            "name" => row.0.to_string(),
+            _ => row.1.to_string(),
+        });
+
+        assert_eq!(filtered, vec![("Carol", 40)]);
+    }
+
+    #[test]
+    fn test_get_distinct_values() {
+        let values = get_distinct_values(["b", "a", "b"].into_iter().map(String::from));
+
+        assert_eq!(values, vec!["a", "b"]);
+    }
+}
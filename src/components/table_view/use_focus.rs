@@ -19,9 +19,29 @@ impl FocusInit {
     }
 }
 
+/// Active cell made of row ID and column key
+#[derive(Clone, Debug, PartialEq)]
+pub struct FocusedCell {
+    pub id: String,
+    pub column: String,
+}
+
+/// Get the column key `step` columns away from `current`, clamped to the ends
+///
+/// Starts from the first column when nothing is focused yet.
+pub fn get_shifted_column_key(column_keys: &[String], current: Option<&String>, step: isize) -> Option<String> {
+    let current_index = current.and_then(|key| column_keys.iter().position(|it| it == key));
+    let next_index = match current_index {
+        Some(index) => index.saturating_add_signed(step).min(column_keys.len().saturating_sub(1)),
+        None => 0,
+    };
+    column_keys.get(next_index).cloned()
+}
+
 // Type aliases for complex function types
 type InitFn = Rc<RefCell<dyn FnMut()>>;
 type SetIdFn = Rc<RefCell<dyn FnMut(Option<String>)>>;
+type SetColumnFn = Rc<RefCell<dyn FnMut(Option<String>)>>;
 type SetActiveFn = Rc<RefCell<dyn FnMut(bool)>>;
 type SetScrollRefFn = Rc<RefCell<dyn FnMut(Option<String>)>>;
 type SetContentRefsFn = Rc<dyn Fn(usize) -> Rc<RefCell<dyn FnMut(Option<String>)>>>;
@@ -34,10 +54,16 @@ pub struct UseFocusResult {
     pub init: InitFn,
     /// Current focused element ID
     pub id: Option<String>,
+    /// Current focused column key
+    pub column: Option<String>,
+    /// Current focused cell (set when both row and column are focused)
+    pub cell: Option<FocusedCell>,
     /// Whether focus is active
     pub active: bool,
     /// Set the ID of the focused element
     pub set_id: SetIdFn,
+    /// Set the key of the focused column
+    pub set_column: SetColumnFn,
     /// Set the active state
     pub set_active: SetActiveFn,
     /// Set the scroll container reference (element ID)
@@ -84,6 +110,7 @@ pub struct UseFocusResult {
 pub fn use_focus(init: Option<FocusInit>) -> UseFocusResult {
     // Create signals for state management
     let id_signal = use_signal(|| init.as_ref().and_then(|i| i.resolve()));
+    let column_signal = use_signal(|| None::<String>);
     let active_signal = use_signal(|| false);
     
     // Create refs for DOM element IDs using use_signal instead of useRef
@@ -94,10 +121,12 @@ pub fn use_focus(init: Option<FocusInit>) -> UseFocusResult {
     let init_fn = {
         let init_value = init.clone();
         let mut id_signal = id_signal;
+        let mut column_signal = column_signal;
         Rc::new(RefCell::new(move || {
             if let Some(ref init) = init_value {
                 id_signal.set(init.resolve());
             }
+            column_signal.set(None);
         }))
     };
     
@@ -109,6 +138,14 @@ pub fn use_focus(init: Option<FocusInit>) -> UseFocusResult {
         }))
     };
     
+    // Create setColumn function
+    let set_column_fn = {
+        let mut column_signal = column_signal;
+        Rc::new(RefCell::new(move |new_column: Option<String>| {
+            column_signal.set(new_column);
+        }))
+    };
+    
     // Create setActive function
     let set_active_fn = {
         let mut active_signal = active_signal;
@@ -161,13 +198,19 @@ pub fn use_focus(init: Option<FocusInit>) -> UseFocusResult {
     
     // Get current state values
     let current_id = id_signal.read().clone();
+    let current_column = column_signal.read().clone();
+    let current_cell = current_id.clone().zip(current_column.clone())
+        .map(|(id, column)| FocusedCell { id, column });
     let current_active = *active_signal.read();
     
     UseFocusResult {
         init: init_fn,
         id: current_id,
+        column: current_column,
+        cell: current_cell,
         active: current_active,
         set_id: set_id_fn,
+        set_column: set_column_fn,
         set_active: set_active_fn,
         set_scroll_ref: set_scroll_ref_fn,
         set_content_refs: set_content_refs_fn,
@@ -178,6 +221,7 @@ pub fn use_focus(init: Option<FocusInit>) -> UseFocusResult {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::render_until_idle;
     
     #[test]
     fn test_use_focus_with_string_init() {
@@ -251,6 +295,54 @@ mod tests {
         dom.rebuild_to_vec();
     }
     
+    #[test]
+    fn test_get_shifted_column_key() {
+        let keys = vec!["id".to_string(), "name".to_string(), "value".to_string()];
+        
+        assert_eq!(get_shifted_column_key(&keys, None, 1), Some("id".to_string()));
+        assert_eq!(get_shifted_column_key(&keys, Some(&"id".to_string()), 1), Some("name".to_string()));
+        assert_eq!(get_shifted_column_key(&keys, Some(&"id".to_string()), -1), Some("id".to_string()));
+        assert_eq!(get_shifted_column_key(&keys, Some(&"value".to_string()), 1), Some("value".to_string()));
+        assert_eq!(get_shifted_column_key(&[], None, 1), None);
+    }
+    
+    #[tokio::test]
+    async fn test_use_focus_column() {
+        use dioxus::prelude::*;
+        
+        // Focus of the latest render
+        type Latest = Rc<RefCell<Option<UseFocusResult>>>;
+        
+        fn app(latest: Latest) -> Element {
+            let focus = use_focus(Some(FocusInit::Value("row-1".to_string())));
+            *latest.borrow_mut() = Some(focus);
+            
+            rsx! { div {} }
+        }
+        
+        let latest: Latest = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(app, latest.clone());
+        dom.rebuild_in_place();
+        
+        // No column is focused initially
+        let focus = latest.borrow().clone().unwrap();
+        assert_eq!(focus.column, None);
+        assert_eq!(focus.cell, None);
+        
+        let column_keys = ["id", "name", "value"].map(str::to_string);
+        // (step, expected column) - shifting past either edge stays on the edge column
+        for (step, expected) in [(1, "id"), (-1, "id"), (1, "name"), (1, "value"), (1, "value"), (-1, "name")] {
+            let focus = latest.borrow().clone().unwrap();
+            let next = get_shifted_column_key(&column_keys, focus.column.as_ref(), step);
+            dom.in_runtime(|| focus.set_column.borrow_mut()(next));
+            render_until_idle(&mut dom).await;
+            
+            let focus = latest.borrow().clone().unwrap();
+            assert_eq!(focus.column.as_deref(), Some(expected));
+            assert_eq!(focus.cell, Some(FocusedCell { id: "row-1".to_string(), column: expected.to_string() }));
+        }
+    }
+    
     #[test]
     fn test_use_focus_set_active() {
         use dioxus::prelude::*;
@@ -24,6 +24,7 @@ pub struct UsePaginationFnParams {
 }
 
 /// Result type for the pagination function hook
+#[derive(Clone)]
 pub struct UsePaginationFnResult {
     /// Set the current page number
     pub set_page: SetPageFn,
@@ -0,0 +1,84 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use crate::types::setter::{SetStateAction, SetterUtils};
+
+type SetExpandedIdsFn = Rc<RefCell<dyn FnMut(SetStateAction<HashSet<String>>)>>;
+type SetByIdFn = Rc<RefCell<dyn FnMut(String)>>;
+
+#[derive(Clone)]
+pub struct UseRowExpansionResult {
+    /// IDs of the rows whose detail panel is shown
+    pub expanded_ids: HashSet<String>,
+    pub set_expanded_ids: SetExpandedIdsFn,
+    pub expand: SetByIdFn,
+    pub collapse: SetByIdFn,
+    pub toggle: SetByIdFn,
+    pub collapse_all: Rc<RefCell<dyn FnMut()>>,
+}
+
+impl UseRowExpansionResult {
+    pub fn is_expanded(&self, id: &str) -> bool {
+        self.expanded_ids.contains(id)
+    }
+}
+
+/// Hook for expanding rows into detail panels
+///
+/// Rows start collapsed.
+pub fn use_row_expansion() -> UseRowExpansionResult {
+    let expanded_ids = use_signal(HashSet::<String>::new);
+
+    let set_expanded_ids = {
+        let mut expanded_ids = expanded_ids;
+        Rc::new(RefCell::new(move |set_state_action: SetStateAction<HashSet<String>>| {
+            let prev = expanded_ids.peek().clone();
+            let next = SetterUtils::to_value(set_state_action, prev.clone());
+            if next != prev {
+                expanded_ids.set(next);
+            }
+        }))
+    };
+
+    let set_by_id = |update: fn(&mut HashSet<String>, String)| -> SetByIdFn {
+        let set_expanded_ids = set_expanded_ids.clone();
+        Rc::new(RefCell::new(move |id: String| {
+            set_expanded_ids.borrow_mut()(SetStateAction::Function(Rc::new(move |mut prev: HashSet<String>| {
+                update(&mut prev, id.clone());
+                prev
+            })));
+        }))
+    };
+
+    let expand = set_by_id(|ids, id| {
+        ids.insert(id);
+    });
+    let collapse = set_by_id(|ids, id| {
+        ids.remove(&id);
+    });
+    let toggle = set_by_id(|ids, id| {
+        if !ids.remove(&id) {
+            ids.insert(id);
+        }
+    });
+
+    let collapse_all = {
+        let set_expanded_ids = set_expanded_ids.clone();
+        Rc::new(RefCell::new(move || {
+            set_expanded_ids.borrow_mut()(SetStateAction::Value(HashSet::new()));
+        }))
+    };
+
+    let current_expanded_ids = expanded_ids.read().clone();
+
+    UseRowExpansionResult {
+        expanded_ids: current_expanded_ids,
+        set_expanded_ids,
+        expand,
+        collapse,
+        toggle,
+        collapse_all,
+    }
+}
@@ -0,0 +1,313 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::components::table_view::get_column_option_map::{get_cell_text, ColumnOptionMap};
+use crate::components::table_view::get_column_totals::get_column_totals;
+use crate::components::table_view::use_table::DataWithId;
+use crate::types::setter::{SetStateAction, SetterUtils};
+
+type SetGroupByFn = Rc<RefCell<dyn FnMut(SetStateAction<Vec<String>>)>>;
+type SetExpandedFn = Rc<RefCell<dyn FnMut(String, bool)>>;
+/// Totals of the columns with `total` set by group ID, then by column key
+pub type GroupTotals = HashMap<String, HashMap<String, f64>>;
+
+/// Prefix of the IDs of group rows
+pub const GROUP_ID_PREFIX: &str = "__group__:";
+
+/// ID of the group row at `path` (the group values from the outermost group)
+pub fn get_group_id(path: &[String]) -> String {
+    format!("{GROUP_ID_PREFIX}{}", path.join("\u{1f}"))
+}
+
+pub fn is_group_id(id: &str) -> bool {
+    id.starts_with(GROUP_ID_PREFIX)
+}
+
+/// Group of a group header row
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RowGroup {
+    /// Key of the column grouped by
+    pub key: String,
+    /// Cell text shared by the rows of the group
+    pub value: String,
+    /// Number of data rows in the group, including nested groups
+    pub count: usize,
+    pub is_expanded: bool,
+}
+
+/// Group sorted rows by the cell texts of `group_by` and flatten the visible tree
+///
+/// Groups are ordered by their first row, so sorting applies both among and within groups.
+/// Group rows hold the first row of their group as `get`, and the rows of collapsed groups
+/// are left out. Render indices are reassigned in the flattened order.
+pub fn get_grouped_rows<T: Clone + Hash + Eq + 'static>(
+    rows: Vec<DataWithId<T>>,
+    group_by: &[String],
+    column_option_map: &ColumnOptionMap<T>,
+    is_expanded: impl Fn(&str) -> bool,
+) -> (Vec<DataWithId<T>>, GroupTotals) {
+    let group_by: Vec<&String> = group_by.iter().filter(|key| column_option_map.contains_key(*key)).collect();
+    let mut flattened = Vec::new();
+    let mut group_totals = HashMap::new();
+    push_groups(rows, &group_by, &[], column_option_map, &is_expanded, &mut flattened, &mut group_totals);
+
+    for (render_index, row) in flattened.iter_mut().enumerate() {
+        row.render_index = Some(render_index);
+    }
+    (flattened, group_totals)
+}
+
+fn push_groups<T: Clone + Hash + Eq + 'static>(
+    rows: Vec<DataWithId<T>>,
+    group_by: &[&String],
+    path: &[String],
+    column_option_map: &ColumnOptionMap<T>,
+    is_expanded: &impl Fn(&str) -> bool,
+    flattened: &mut Vec<DataWithId<T>>,
+    group_totals: &mut GroupTotals,
+) {
+    let Some((key, rest)) = group_by.split_first() else {
+        flattened.extend(rows.into_iter().map(|mut row| {
+            row.depth = path.len();
+            row.group_path = path.to_vec();
+            row
+        }));
+        return;
+    };
+    let column = &column_option_map[*key];
+
+    // Buckets in order of their first row
+    let mut groups: Vec<(String, Vec<DataWithId<T>>)> = Vec::new();
+    let mut group_indices: HashMap<String, usize> = HashMap::new();
+    for row in rows {
+        let value = get_cell_text(column, &row.get, &row.id, row.data_index);
+        match group_indices.get(&value) {
+            Some(&index) => groups[index].1.push(row),
+            None => {
+                group_indices.insert(value.clone(), groups.len());
+                groups.push((value, vec![row]));
+            }
+        }
+    }
+
+    for (value, group_rows) in groups {
+        let mut group_path = path.to_vec();
+        group_path.push(value.clone());
+        let id = get_group_id(&group_path);
+        let expanded = is_expanded(&id);
+
+        group_totals.insert(id.clone(), get_column_totals(column_option_map, &group_rows));
+        flattened.push(DataWithId {
+            get: group_rows[0].get.clone(),
+            id: id.clone(),
+            data_index: group_rows[0].data_index,
+            render_index: None,
+            local_index: None,
+            depth: path.len(),
+            group_path: path.to_vec(),
+            group: Some(RowGroup {
+                key: (*key).clone(),
+                value,
+                count: group_rows.len(),
+                is_expanded: expanded,
+            }),
+            tree: None,
+        });
+        if expanded {
+            push_groups(group_rows, rest, &group_path, column_option_map, is_expanded, flattened, group_totals);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct UseRowGroupingResult {
+    pub init: Rc<RefCell<dyn FnMut()>>,
+    /// Column keys grouped by, from the outermost group
+    pub group_by: Vec<String>,
+    pub set_group_by: SetGroupByFn,
+    /// Groups expanded unless toggled
+    pub default_expanded: bool,
+    /// Group IDs toggled from `default_expanded`
+    pub toggled_ids: HashSet<String>,
+    pub set_expanded: SetExpandedFn,
+    pub toggle: Rc<RefCell<dyn FnMut(String)>>,
+    pub expand_all: Rc<RefCell<dyn FnMut()>>,
+    pub collapse_all: Rc<RefCell<dyn FnMut()>>,
+}
+
+impl UseRowGroupingResult {
+    pub fn is_expanded(&self, group_id: &str) -> bool {
+        self.default_expanded != self.toggled_ids.contains(group_id)
+    }
+}
+
+/// Hook for grouping rows by columns, with expandable groups
+///
+/// Groups start expanded.
+pub fn use_row_grouping(init: impl Fn() -> Vec<String> + Clone + 'static) -> UseRowGroupingResult {
+    let group_by = use_signal(&init);
+    let default_expanded = use_signal(|| true);
+    let toggled_ids = use_signal(HashSet::<String>::new);
+
+    let set_group_by = {
+        let mut group_by = group_by;
+        Rc::new(RefCell::new(move |set_state_action: SetStateAction<Vec<String>>| {
+            let prev = group_by.peek().clone();
+            group_by.set(SetterUtils::to_value(set_state_action, prev));
+        }))
+    };
+
+    let set_expanded = {
+        let mut toggled_ids = toggled_ids;
+        Rc::new(RefCell::new(move |group_id: String, expanded: bool| {
+            // Toggled when it differs from the default
+            let toggled = expanded != *default_expanded.peek();
+            if toggled_ids.peek().contains(&group_id) != toggled {
+                toggled_ids.with_mut(|ids| {
+                    if toggled {
+                        ids.insert(group_id);
+                    } else {
+                        ids.remove(&group_id);
+                    }
+                });
+            }
+        }))
+    };
+
+    let toggle = {
+        let mut toggled_ids = toggled_ids;
+        Rc::new(RefCell::new(move |group_id: String| {
+            toggled_ids.with_mut(|ids| {
+                if !ids.remove(&group_id) {
+                    ids.insert(group_id);
+                }
+            });
+        }))
+    };
+
+    let set_all = move |expanded: bool| -> Rc<RefCell<dyn FnMut()>> {
+        let (mut default_expanded, mut toggled_ids) = (default_expanded, toggled_ids);
+        Rc::new(RefCell::new(move || {
+            default_expanded.set(expanded);
+            toggled_ids.set(HashSet::new());
+        }))
+    };
+
+    let init_fn = {
+        let mut group_by = group_by;
+        let reset_expanded = set_all(true);
+        Rc::new(RefCell::new(move || {
+            group_by.set(init());
+            reset_expanded.borrow_mut()();
+        }))
+    };
+
+    let current_group_by = group_by.read().clone();
+    let current_default_expanded = *default_expanded.read();
+    let current_toggled_ids = toggled_ids.read().clone();
+
+    UseRowGroupingResult {
+        init: init_fn,
+        group_by: current_group_by,
+        set_group_by,
+        default_expanded: current_default_expanded,
+        toggled_ids: current_toggled_ids,
+        set_expanded,
+        toggle,
+        expand_all: set_all(true),
+        collapse_all: set_all(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::table_view::get_column_option_map::{default_column_option, ColumnOption};
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Row {
+        team: &'static str,
+        role: &'static str,
+        value: i32,
+    }
+
+    fn create_column_option_map() -> ColumnOptionMap<Row> {
+        let column = |key: &str, mapper: fn(&Row) -> String| ColumnOption {
+            value_mapper: Rc::new(move |raw, _| raw.downcast_ref::<Row>().map(mapper).unwrap_or_default()),
+            ..default_column_option(key)
+        };
+        let mut column_option_map = HashMap::new();
+        column_option_map.insert("team".to_string(), column("team", |row| row.team.to_string()));
+        column_option_map.insert("role".to_string(), column("role", |row| row.role.to_string()));
+        column_option_map.insert("value".to_string(), ColumnOption {
+            total: true,
+            ..column("value", |row| row.value.to_string())
+        });
+        column_option_map
+    }
+
+    fn create_rows() -> Vec<DataWithId<Row>> {
+        [("b", "dev", 1), ("a", "dev", 2), ("b", "ops", 3), ("b", "dev", 4)]
+            .into_iter()
+            .enumerate()
+            .map(|(index, (team, role, value))| DataWithId {
+                get: Row { team, role, value },
+                id: index.to_string(),
+                data_index: index,
+                render_index: Some(index),
+                local_index: None,
+                depth: 0,
+                group_path: Vec::new(),
+                group: None,
+                tree: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_get_grouped_rows() {
+        let (rows, group_totals) = get_grouped_rows(
+            create_rows(),
+            &["team".to_string(), "role".to_string()],
+            &create_column_option_map(),
+            |id| id != get_group_id(&["b".to_string(), "ops".to_string()]),
+        );
+        let summary: Vec<(String, usize)> = rows.iter().map(|row| (row.id.clone(), row.depth)).collect();
+
+        assert_eq!(summary, vec![
+            (get_group_id(&["b".to_string()]), 0),
+            (get_group_id(&["b".to_string(), "dev".to_string()]), 1),
+            ("0".to_string(), 2),
+            ("3".to_string(), 2),
+            (get_group_id(&["b".to_string(), "ops".to_string()]), 1),
+            (get_group_id(&["a".to_string()]), 0),
+            (get_group_id(&["a".to_string(), "dev".to_string()]), 1),
+            ("1".to_string(), 2),
+        ]);
+        assert_eq!(rows[0].group.as_ref().map(|group| group.count), Some(3));
+        assert_eq!(rows[2].group_path, vec!["b".to_string(), "dev".to_string()]);
+        assert_eq!(rows.iter().map(|row| row.render_index).collect::<Vec<_>>(), (0..8).map(Some).collect::<Vec<_>>());
+        // Collapsed groups still total every row
+        assert_eq!(group_totals[&get_group_id(&["b".to_string()])]["value"], 8.0);
+        assert_eq!(group_totals[&get_group_id(&["b".to_string(), "ops".to_string()])]["value"], 3.0);
+    }
+
+    #[test]
+    fn test_use_row_grouping() {
+        let mut dom = VirtualDom::new(|| {
+            let row_grouping = use_row_grouping(|| vec!["team".to_string()]);
+
+            assert_eq!(row_grouping.group_by, vec!["team".to_string()]);
+            assert!(row_grouping.is_expanded(&get_group_id(&["a".to_string()])));
+            assert!(is_group_id(&get_group_id(&["a".to_string()])));
+            assert!(!is_group_id("a"));
+
+            rsx! { div {} }
+        });
+
+        dom.rebuild_to_vec();
+    }
+}
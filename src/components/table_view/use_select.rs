@@ -3,97 +3,195 @@ use std::rc::Rc;
 use std::cell::RefCell;
 
 use crate::types::setter::{SetStateAction, SetterUtils};
+use crate::components::table_view::use_focus_fn::{GetIdFromIndexFn, GetIndexFromIdFn};
+
+// Type aliases for complex function types
+type SetIdsFn = Rc<RefCell<dyn FnMut(SetStateAction<Vec<String>>) -> SelectResult>>;
+type SetByIdFn = Rc<dyn Fn(String) -> Rc<RefCell<dyn FnMut(SetStateAction<bool>) -> SelectResult>>>;
+type SetAnchorIdFn = Rc<RefCell<dyn FnMut(Option<String>)>>;
+type SelectRangeFn = Rc<RefCell<dyn FnMut(String, String) -> SelectResult>>;
+type SelectAllFn = Rc<RefCell<dyn FnMut() -> SelectResult>>;
 
 #[derive(Clone)]
 pub struct UseSelectResult {
     pub init: Rc<RefCell<dyn FnMut()>>,
     pub ids: Vec<String>,
-    pub set_ids: Rc<RefCell<dyn FnMut(SetStateAction<Vec<String>>) -> SelectResult>>,
+    pub set_ids: SetIdsFn,
     pub unset_all: Rc<RefCell<dyn FnMut()>>,
-    pub set_by_id: Rc<dyn Fn(String) -> Rc<RefCell<dyn FnMut(SetStateAction<bool>) -> SelectResult>>>,
+    pub set_by_id: SetByIdFn,
     pub toggle_by_id: Rc<RefCell<dyn FnMut(String)>>,
+    /// Anchor of the range selection (None falls back to the focused row)
+    pub anchor_id: Option<String>,
+    /// Set the anchor of the range selection
+    pub set_anchor_id: SetAnchorIdFn,
+    /// Select every row between anchor and target (inclusive) in render order
+    pub select_range: SelectRangeFn,
+    /// Select every row in render order
+    pub select_all: SelectAllFn,
+}
+
+/// Render order used to resolve range selections
+#[derive(Clone)]
+pub struct SelectRenderOrder {
+    pub get_render_index_from_id: GetIndexFromIdFn,
+    pub get_id_from_render_index: GetIdFromIndexFn,
+    pub max_render_index: usize,
+    /// Rows that can be selected (others are dropped from any selection, e.g. group rows)
+    pub is_selectable: Rc<dyn Fn(&str) -> bool>,
+}
+
+/// Get the IDs between anchor and target (inclusive) in render order
+///
+/// The result follows the direction from anchor to target, so the target is always last.
+/// Returns just the target when either ID is not rendered.
+pub fn get_range_ids(anchor_id: &str, target_id: &str, render_order: &SelectRenderOrder) -> Vec<String> {
+    let anchor_index = (render_order.get_render_index_from_id)(Some(anchor_id.to_string()));
+    let target_index = (render_order.get_render_index_from_id)(Some(target_id.to_string()));
+    
+    match (anchor_index, target_index) {
+        (Some(anchor_index), Some(target_index)) => {
+            let indices: Vec<usize> = if anchor_index <= target_index {
+                (anchor_index..=target_index).collect()
+            } else {
+                (target_index..=anchor_index).rev().collect()
+            };
+            indices
+                .into_iter()
+                .filter_map(|index| (render_order.get_id_from_render_index)(Some(index)))
+                .collect()
+        }
+        _ => vec![target_id.to_string()],
+    }
 }
 
 pub struct SelectResult {
     pub default_prevented: bool,
 }
 
+/// Selection change notified before it is applied
+///
+/// Calling `prevent_default` vetoes the change. The event can be cloned and
+/// `apply_callback` called later, e.g. after an async confirmation.
+#[derive(Clone)]
 pub struct SelectEvent {
+    /// Selected IDs before the change
+    pub prev_ids: Vec<String>,
+    /// Selected IDs after the change
+    pub next_ids: Vec<String>,
+    /// IDs that become selected
+    pub added_ids: Vec<String>,
+    /// IDs that become unselected
+    pub removed_ids: Vec<String>,
     prevent_default: Rc<RefCell<bool>>,
     apply_callback: Rc<RefCell<dyn FnMut()>>,
 }
 
 impl SelectEvent {
+    fn new(prev_ids: Vec<String>, next_ids: Vec<String>, apply_callback: Rc<RefCell<dyn FnMut()>>) -> Self {
+        let added_ids = next_ids.iter()
+            .filter(|id| !prev_ids.contains(id))
+            .cloned()
+            .collect();
+        let removed_ids = prev_ids.iter()
+            .filter(|id| !next_ids.contains(id))
+            .cloned()
+            .collect();
+        
+        Self {
+            prev_ids,
+            next_ids,
+            added_ids,
+            removed_ids,
+            prevent_default: Rc::new(RefCell::new(false)),
+            apply_callback,
+        }
+    }
+    
     pub fn prevent_default(&self) {
         *self.prevent_default.borrow_mut() = true;
     }
     
+    pub fn is_default_prevented(&self) -> bool {
+        *self.prevent_default.borrow()
+    }
+    
+    /// Apply the change
+    ///
+    /// Only the first call takes effect, and only while the selection is still `prev_ids`.
     pub fn apply_callback(&self) {
         self.apply_callback.borrow_mut()();
     }
 }
 
+/// Called before the selection changes
+pub type OnSelectChangeFn = Rc<dyn Fn(&SelectEvent)>;
+/// Called with the selected IDs after the selection changed
+pub type OnSelectAppliedFn = Rc<dyn Fn(&[String])>;
+
 pub fn use_select(
     init: impl Fn() -> Vec<String> + Clone + 'static,
     select_many: bool,
     changeable: bool,
     cancelable: bool,
-    on_change: Option<Rc<dyn Fn(&SelectEvent)>>,
+    on_change: Option<OnSelectChangeFn>,
+    on_applied: Option<OnSelectAppliedFn>,
+    render_order: SelectRenderOrder,
 ) -> UseSelectResult {
-    let selected_ids = use_signal(|| init());
+    let selected_ids = use_signal(&init);
+    let anchor_id = use_signal(|| None::<String>);
     
     let set_selected_ids = {
-        let mut selected_ids_for_closure = selected_ids;
-        let _init = init.clone();
-        let on_change = on_change.clone();
-        
+        let is_selectable = render_order.is_selectable.clone();
         Rc::new(RefCell::new(move |set_state_action: SetStateAction<Vec<String>>| -> SelectResult {
-            let default_prevented = Rc::new(RefCell::new(false));
-            let default_prevented_clone = default_prevented.clone();
-            
-            let mut should_return_early = false;
-            let mut next_value: Vec<String> = Vec::new();
-            
-            // First, compute the next value
-            let current = selected_ids_for_closure.read().clone();
-            let next_raw = SetterUtils::to_value(set_state_action, current.clone());
+            let current = selected_ids.read().clone();
+            let next_raw: Vec<String> = SetterUtils::to_value(set_state_action, current.clone())
+                .into_iter()
+                .filter(|id| is_selectable(id))
+                .collect();
             let next = if select_many {
                 next_raw
             } else {
                 next_raw.into_iter().take(1).collect()
             };
             
-            if changeable && current != next {
-                if let Some(ref on_change_fn) = on_change {
-                    let next_for_callback = next.clone();
-                    let apply_callback = {
-                        Rc::new(RefCell::new(move || {
-                            // This will be called later by the user
-                            // We can't modify the signal here due to borrowing rules
-                            // The actual update happens below
-                        }))
-                    };
-                    
-                    let event = SelectEvent {
-                        prevent_default: default_prevented_clone.clone(),
-                        apply_callback,
-                    };
-                    
-                    on_change_fn(&event);
-                    
-                    if *default_prevented_clone.borrow() {
-                        should_return_early = true;
+            if current == next {
+                return SelectResult { default_prevented: false };
+            }
+            
+            let apply_callback: Rc<RefCell<dyn FnMut()>> = {
+                let mut selected_ids = selected_ids;
+                let prev = current.clone();
+                let next = next.clone();
+                let on_applied = on_applied.clone();
+                let mut applied = false;
+                
+                Rc::new(RefCell::new(move || {
+                    // A change made for an older selection would undo the later changes
+                    if applied || *selected_ids.peek() != prev {
+                        return;
+                    }
+                    applied = true;
+                    selected_ids.set(next.clone());
+                    if let Some(on_applied) = &on_applied {
+                        on_applied(&next);
                     }
+                }))
+            };
+            
+            let event = SelectEvent::new(current, next, apply_callback);
+            
+            if changeable {
+                if let Some(on_change) = &on_change {
+                    on_change(&event);
                 }
             }
             
-            if !should_return_early {
-                selected_ids_for_closure.set(next);
+            let default_prevented = event.is_default_prevented();
+            if !default_prevented {
+                event.apply_callback();
             }
             
-            SelectResult {
-                default_prevented: should_return_early,
-            }
+            SelectResult { default_prevented }
         }))
     };
     
@@ -150,26 +248,59 @@ pub fn use_select(
     };
     
     let toggle_by_id = {
-        let mut selected_ids = selected_ids;
+        let set_selected_ids = set_selected_ids.clone();
         
         Rc::new(RefCell::new(move |id: String| {
-            selected_ids.with_mut(|prev_ids| {
+            set_selected_ids.borrow_mut()(SetStateAction::Function(Rc::new(move |mut prev_ids: Vec<String>| {
                 let prev = prev_ids.contains(&id);
                 
                 if !cancelable && prev {
-                    return;
+                    return prev_ids;
                 }
                 
                 if prev {
                     prev_ids.retain(|it| it != &id);
                 } else {
-                    prev_ids.insert(0, id);
+                    prev_ids.insert(0, id.clone());
                 }
-            });
+                prev_ids
+            })));
+        }))
+    };
+    
+    let set_anchor_id = {
+        let mut anchor_id = anchor_id;
+        
+        Rc::new(RefCell::new(move |id: Option<String>| {
+            anchor_id.set(id);
+        }))
+    };
+    
+    let select_range = {
+        let set_selected_ids = set_selected_ids.clone();
+        let render_order = render_order.clone();
+        
+        Rc::new(RefCell::new(move |anchor_id: String, target_id: String| -> SelectResult {
+            // Keep the target first so single selection keeps the target
+            let mut range_ids = get_range_ids(&anchor_id, &target_id, &render_order);
+            range_ids.reverse();
+            set_selected_ids.borrow_mut()(SetStateAction::Value(range_ids))
+        }))
+    };
+    
+    let select_all = {
+        let set_selected_ids = set_selected_ids.clone();
+        
+        Rc::new(RefCell::new(move || -> SelectResult {
+            let all_ids = (0..render_order.max_render_index)
+                .filter_map(|index| (render_order.get_id_from_render_index)(Some(index)))
+                .collect();
+            set_selected_ids.borrow_mut()(SetStateAction::Value(all_ids))
         }))
     };
     
     let current_ids = selected_ids.read().clone();
+    let current_anchor_id = anchor_id.read().clone();
     
     UseSelectResult {
         init: init_fn,
@@ -178,5 +309,286 @@ pub fn use_select(
         unset_all,
         set_by_id,
         toggle_by_id,
+        anchor_id: current_anchor_id,
+        set_anchor_id,
+        select_range,
+        select_all,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::render_until_idle;
+    
+    fn create_render_order(ids: &[&str]) -> SelectRenderOrder {
+        let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        let ids_for_index = ids.clone();
+        SelectRenderOrder {
+            get_render_index_from_id: Rc::new(move |id: Option<String>| {
+                id.and_then(|id| ids_for_index.iter().position(|it| *it == id))
+            }),
+            max_render_index: ids.len(),
+            get_id_from_render_index: Rc::new(move |index: Option<usize>| {
+                index.and_then(|index| ids.get(index).cloned())
+            }),
+            is_selectable: Rc::new(|id: &str| !id.starts_with('_')),
+        }
+    }
+    
+    #[test]
+    fn test_get_range_ids_follows_render_order() {
+        let render_order = create_render_order(&["c", "a", "d", "b"]);
+        
+        assert_eq!(get_range_ids("a", "b", &render_order), vec!["a", "d", "b"]);
+        assert_eq!(get_range_ids("b", "a", &render_order), vec!["b", "d", "a"]);
+        assert_eq!(get_range_ids("d", "d", &render_order), vec!["d"]);
+    }
+    
+    #[test]
+    fn test_get_range_ids_unknown_id() {
+        let render_order = create_render_order(&["a", "b"]);
+        
+        assert_eq!(get_range_ids("x", "b", &render_order), vec!["b"]);
+    }
+    
+    #[tokio::test]
+    async fn test_use_select_range() {
+        use dioxus::prelude::*;
+        
+        // Selections of the latest render (multiple, single) and the change events
+        #[derive(Clone)]
+        struct Recorded {
+            latest: Rc<RefCell<Option<(UseSelectResult, UseSelectResult)>>>,
+            events: Rc<RefCell<Vec<SelectEvent>>>,
+        }
+        
+        fn app(recorded: Recorded) -> Element {
+            let on_change: OnSelectChangeFn = Rc::new({
+                let events = recorded.events.clone();
+                move |event: &SelectEvent| events.borrow_mut().push(event.clone())
+            });
+            let render_order = create_render_order(&["a", "b", "c", "d"]);
+            let select_many = use_select(|| vec!["d".to_string()], true, true, true, Some(on_change), None, render_order.clone());
+            let select_one = use_select(Vec::new, false, true, true, None, None, render_order);
+            *recorded.latest.borrow_mut() = Some((select_many, select_one));
+            
+            rsx! { div {} }
+        }
+        
+        let recorded = Recorded {
+            latest: Rc::new(RefCell::new(None)),
+            events: Rc::new(RefCell::new(Vec::new())),
+        };
+        let mut dom = VirtualDom::new_with_props(app, recorded.clone());
+        dom.rebuild_in_place();
+        
+        let latest = || recorded.latest.borrow().clone().unwrap();
+        let ids = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+        assert_eq!(latest().0.ids, ids(&["d"]));
+        assert_eq!(latest().0.anchor_id, None);
+        
+        // (anchor, target, expected IDs, added, removed), or select all without an anchor
+        let steps = [
+            (Some(("c", "a")), ids(&["a", "b", "c"]), ids(&["a", "b", "c"]), ids(&["d"])),
+            (Some(("a", "c")), ids(&["c", "b", "a"]), ids(&[]), ids(&[])),
+            (None, ids(&["a", "b", "c", "d"]), ids(&["d"]), ids(&[])),
+        ];
+        for (range, expected, added, removed) in steps {
+            let (select, _) = latest();
+            let result = dom.in_runtime(|| match range {
+                Some((anchor, target)) => select.select_range.borrow_mut()(anchor.to_string(), target.to_string()),
+                None => select.select_all.borrow_mut()(),
+            });
+            assert!(!result.default_prevented);
+            render_until_idle(&mut dom).await;
+            
+            let event = recorded.events.borrow().last().cloned().unwrap();
+            assert_eq!(event.prev_ids, select.ids);
+            assert_eq!(event.next_ids, expected);
+            assert_eq!(event.added_ids, added);
+            assert_eq!(event.removed_ids, removed);
+            assert_eq!(latest().0.ids, expected);
+        }
+        assert_eq!(recorded.events.borrow().len(), 3);
+        
+        // Single selection keeps the target of the range
+        let (_, select_one) = latest();
+        dom.in_runtime(|| select_one.select_range.borrow_mut()("a".to_string(), "c".to_string()));
+        render_until_idle(&mut dom).await;
+        assert_eq!(latest().1.ids, ids(&["c"]));
+    }
+    
+    #[test]
+    fn test_use_select_skips_unselectable() {
+        use dioxus::prelude::*;
+        
+        let mut dom = VirtualDom::new(|| {
+            let changes = use_hook(|| Rc::new(RefCell::new(Vec::<Vec<String>>::new())));
+            let select = use_select(Vec::new, true, true, true, Some(Rc::new({
+                let changes = changes.clone();
+                move |event: &SelectEvent| changes.borrow_mut().push(event.next_ids.clone())
+            })), None, create_render_order(&["a", "_group", "b"]));
+            
+            select.toggle_by_id.borrow_mut()("_group".to_string());
+            select.select_range.borrow_mut()("a".to_string(), "b".to_string());
+            
+            assert_eq!(*changes.borrow(), vec![vec!["b".to_string(), "a".to_string()]]);
+            
+            rsx! { div {} }
+        });
+        
+        dom.rebuild_to_vec();
+    }
+    
+    #[test]
+    fn test_select_event_diff() {
+        let event = SelectEvent::new(
+            vec!["a".to_string(), "b".to_string()],
+            vec!["c".to_string(), "a".to_string()],
+            Rc::new(RefCell::new(|| {})),
+        );
+        
+        assert_eq!(event.added_ids, vec!["c"]);
+        assert_eq!(event.removed_ids, vec!["b"]);
+        assert!(!event.is_default_prevented());
+        
+        event.prevent_default();
+        assert!(event.is_default_prevented());
+    }
+    
+    #[test]
+    fn test_use_select_prevent_default_and_apply_later() {
+        use dioxus::prelude::*;
+        
+        let mut dom = VirtualDom::new(|| {
+            let pending = use_hook(|| Rc::new(RefCell::new(None::<SelectEvent>)));
+            let applied = use_hook(|| Rc::new(RefCell::new(Vec::<Vec<String>>::new())));
+            
+            let select = use_select(
+                || vec!["a".to_string()],
+                true,
+                true,
+                true,
+                Some(Rc::new({
+                    let pending = pending.clone();
+                    move |event: &SelectEvent| {
+                        event.prevent_default();
+                        *pending.borrow_mut() = Some(event.clone());
+                    }
+                })),
+                Some(Rc::new({
+                    let applied = applied.clone();
+                    move |ids: &[String]| applied.borrow_mut().push(ids.to_vec())
+                })),
+                create_render_order(&["a", "b"]),
+            );
+            
+            select.toggle_by_id.borrow_mut()("b".to_string());
+            
+            let event = pending.borrow_mut().take().expect("on_change is called");
+            assert_eq!(event.prev_ids, vec!["a"]);
+            assert_eq!(event.next_ids, vec!["b", "a"]);
+            assert_eq!(event.added_ids, vec!["b"]);
+            assert!(event.removed_ids.is_empty());
+            assert!(applied.borrow().is_empty());
+            
+            // e.g. after an async confirmation
+            event.apply_callback();
+            event.apply_callback();
+            assert_eq!(*applied.borrow(), vec![vec!["b".to_string(), "a".to_string()]]);
+            
+            rsx! { div {} }
+        });
+        
+        dom.rebuild_to_vec();
+    }
+    
+    #[tokio::test]
+    async fn test_use_select_apply_callback_applies_once() {
+        use dioxus::prelude::*;
+        
+        #[derive(Clone)]
+        struct Recorded {
+            latest: Rc<RefCell<Option<UseSelectResult>>>,
+            pending: Rc<RefCell<Vec<SelectEvent>>>,
+            applied: Rc<RefCell<Vec<Vec<String>>>>,
+        }
+        
+        fn app(recorded: Recorded) -> Element {
+            let select = use_select(
+                || vec!["a".to_string()],
+                true,
+                true,
+                true,
+                Some(Rc::new({
+                    let pending = recorded.pending.clone();
+                    move |event: &SelectEvent| {
+                        event.prevent_default();
+                        pending.borrow_mut().push(event.clone());
+                    }
+                })),
+                Some(Rc::new({
+                    let applied = recorded.applied.clone();
+                    move |ids: &[String]| applied.borrow_mut().push(ids.to_vec())
+                })),
+                create_render_order(&["a", "b"]),
+            );
+            *recorded.latest.borrow_mut() = Some(select);
+            
+            rsx! { div {} }
+        }
+        
+        let recorded = Recorded {
+            latest: Rc::new(RefCell::new(None)),
+            pending: Rc::new(RefCell::new(Vec::new())),
+            applied: Rc::new(RefCell::new(Vec::new())),
+        };
+        let mut dom = VirtualDom::new_with_props(app, recorded.clone());
+        dom.rebuild_in_place();
+        
+        let select = recorded.latest.borrow().clone().unwrap();
+        dom.in_runtime(|| select.toggle_by_id.borrow_mut()("b".to_string()));
+        
+        // Prevented: nothing is applied until the callback runs
+        let event = recorded.pending.borrow_mut().pop().expect("on_change is called");
+        assert!(event.is_default_prevented());
+        assert!(recorded.applied.borrow().is_empty());
+        assert_eq!(recorded.latest.borrow().as_ref().unwrap().ids, vec!["a"]);
+        
+        // e.g. after an async confirmation, with repeated calls ignored
+        dom.in_runtime(|| {
+            event.apply_callback();
+            event.apply_callback();
+        });
+        render_until_idle(&mut dom).await;
+        assert_eq!(recorded.latest.borrow().as_ref().unwrap().ids, vec!["b", "a"]);
+        assert_eq!(*recorded.applied.borrow(), vec![vec!["b".to_string(), "a".to_string()]]);
+        
+        // An applied callback doesn't run again after a later change
+        let select = recorded.latest.borrow().clone().unwrap();
+        dom.in_runtime(|| select.toggle_by_id.borrow_mut()("a".to_string()));
+        let later = recorded.pending.borrow_mut().pop().expect("on_change is called");
+        dom.in_runtime(|| {
+            later.apply_callback();
+            event.apply_callback();
+        });
+        render_until_idle(&mut dom).await;
+        assert_eq!(recorded.latest.borrow().as_ref().unwrap().ids, vec!["b"]);
+        assert_eq!(recorded.applied.borrow().len(), 2);
+        
+        // A change never applied but made for an older selection is rejected
+        let select = recorded.latest.borrow().clone().unwrap();
+        dom.in_runtime(|| select.toggle_by_id.borrow_mut()("a".to_string()));
+        let stale = recorded.pending.borrow_mut().pop().expect("on_change is called");
+        dom.in_runtime(|| select.unset_all.borrow_mut()());
+        let current = recorded.pending.borrow_mut().pop().expect("on_change is called");
+        dom.in_runtime(|| {
+            current.apply_callback();
+            stale.apply_callback();
+        });
+        render_until_idle(&mut dom).await;
+        assert!(recorded.latest.borrow().as_ref().unwrap().ids.is_empty());
+        assert_eq!(recorded.applied.borrow().len(), 3);
     }
 }
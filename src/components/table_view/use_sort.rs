@@ -104,9 +104,10 @@ where
         }) as SetOrderMapFn<K>
     };
     
-    let get_sorted_with_index = use_memo(move || {
-        get_sorted_with_index_fn(order_entries.read().clone(), asc_sorter_map.clone(), data.clone())
-    });
+    // Data is not a signal, so track it explicitly to re-sort when it changes (e.g. filtering)
+    let get_sorted_with_index = use_memo(use_reactive((&data,), move |(data,)| {
+        get_sorted_with_index_fn(order_entries.read().clone(), asc_sorter_map.clone(), data)
+    }));
     
     let sorted_with_index = get_sorted_with_index();
     let sorted_indices: Vec<usize> = sorted_with_index.iter().map(|it| it.index).collect();
@@ -217,7 +218,7 @@ where
     }
 }
 
-fn get_sorted_with_index_fn<T, K>(
+pub(crate) fn get_sorted_with_index_fn<T, K>(
     sort_order_entries: Vec<(K, Order)>,
     asc_sorter_map: AscSorterMap<K, T>,
     data: Vec<T>,
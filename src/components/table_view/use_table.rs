@@ -3,15 +3,28 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::hash::Hash;
 
+use dioxus::prelude::*;
+
 use crate::components::render::use_virtual_scroll::{use_virtual_scroll, UseVirtualScrollParams};
-use crate::components::table_view::get_column_option_map::{get_column_option_map, RenderMap, ColumnOptionMap};
+use crate::components::table_view::data_source::{use_data_source, DataQuery, DataSource};
+use crate::components::table_view::get_column_option_map::{get_cell_text, get_column_option_map, get_ordered_column_options, RenderMap, ColumnOptionMap};
+use crate::components::table_view::use_column_order::{use_column_order, UseColumnOrderResult};
+use crate::components::table_view::get_column_totals::{get_column_totals, TotalScope};
+use crate::components::table_view::use_column_visibility::{apply_column_visibility, use_column_visibility, UseColumnVisibilityResult};
+use crate::components::table_view::use_column_virtualization::{use_column_virtualization, ColumnVirtualizationOptions, UseColumnVirtualizationResult};
 use crate::components::table_view::use_column_widthes::{use_column_widths, UseColumnWidthsResult};
+use crate::components::table_view::use_filter::{get_distinct_values, get_filtered, use_filter, CustomFilterFn, FilterKind, FilterValue, SetFilterFn, UseFilterResult};
 use crate::components::table_view::use_focus::{use_focus, FocusInit};
-use crate::components::table_view::use_focus_fn::{use_focus_fn, UseFocusFnParams, FocusByIdFn};
+use crate::components::table_view::use_focus_fn::{use_focus_fn, UseFocusFnParams, FocusByIdFn, FocusByRenderIndexOptions};
 use crate::components::table_view::use_pagination::{use_pagination, UsePaginationParams, UsePaginationResult};
 use crate::components::table_view::use_pagination_fn::{use_pagination_fn, UsePaginationFnParams, UsePaginationFnResult};
-use crate::components::table_view::use_select::{use_select, UseSelectResult};
+use crate::components::table_view::use_row_expansion::{use_row_expansion, UseRowExpansionResult};
+use crate::components::table_view::use_row_grouping::{get_grouped_rows, is_group_id, use_row_grouping, GroupTotals, RowGroup, UseRowGroupingResult};
+use crate::components::table_view::use_select::{use_select, OnSelectChangeFn, SelectRenderOrder, UseSelectResult};
 use crate::components::table_view::use_sort::{use_sort, UseSortResult, Order};
+use crate::components::table_view::use_tree_data::{use_tree_data, GetChildrenFn, LoadChildrenFn, TreeDataOptions, TreeNode, UseTreeDataResult};
+use crate::components::table_view::use_table_search::{get_row_score, use_table_search, TableSearchOptions, UseTableSearchResult};
+use crate::types::setter::{SetStateAction, SetterUtils};
 
 /// Data with ID and indices for table management
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -29,6 +42,14 @@ where
     pub render_index: Option<usize>,
     /// Local index within the current page (optional, set when paginated)
     pub local_index: Option<usize>,
+    /// Nesting level under the row groups (`0` when not grouped)
+    pub depth: usize,
+    /// Values of the groups containing the row, from the outermost group
+    pub group_path: Vec<String>,
+    /// Set on group header rows
+    pub group: Option<RowGroup>,
+    /// Set on the rows of tree data
+    pub tree: Option<TreeNode>,
 }
 
 /// Default pagination configuration
@@ -45,14 +66,14 @@ pub struct DefaultPagination {
 pub type GetDataIdFn<T> = Rc<dyn Fn(&T, usize) -> String>;
 
 // Type alias for the selection callback
-pub type OnSelectCallback = Rc<RefCell<dyn FnMut(Vec<String>)>>;
+pub type OnSelectCallback<T> = Rc<RefCell<dyn FnMut(Vec<DataWithId<T>>)>>;
 
 pub struct UseTableParams<T: Clone + Hash + Eq + 'static> {
     /// The data to display in the table
     pub data: Vec<T>,
     /// Table rendering configuration
     pub render_map: RenderMap<T>,
-    /// Function to generate unique ID for each data item
+    /// Function to generate unique ID for each data item (from the item and its index in `data`)
     pub get_data_id: Option<GetDataIdFn<T>>,
     /// Initial pagination settings (false to disable pagination)
     pub default_pagination: Option<DefaultPagination>,
@@ -60,6 +81,31 @@ pub struct UseTableParams<T: Clone + Hash + Eq + 'static> {
     pub default_focus: Option<String>,
     /// Initial sort configuration
     pub default_sort: Option<Vec<(String, Order)>>,
+    /// Initial column order by key (missing columns follow in key order)
+    pub default_column_order: Option<Vec<String>>,
+    /// Initial column filters
+    pub default_filter: Option<Vec<(String, FilterValue)>>,
+    /// Global quick search across visible columns (`None` disables it)
+    pub search: Option<TableSearchOptions>,
+    /// Server-side data source (`data` is ignored when set)
+    ///
+    /// Filtering, searching, sorting and paging are delegated to the source,
+    /// and only the rows of the current page are held.
+    pub data_source: Option<Rc<dyn DataSource<T>>>,
+    /// Render only the content columns around the horizontal view (`None` disables it)
+    pub column_virtualization: Option<ColumnVirtualizationOptions>,
+    /// Rows the totals of columns with `total` are computed over
+    /// Default is `TotalScope::Filtered` (a data source only has the current page)
+    pub total_scope: Option<TotalScope>,
+    /// Initial column keys to group rows by, from the outermost group
+    /// Ignored with a data source
+    pub group_by: Option<Vec<String>>,
+    /// Children of a row, making the rows tree data with expandable rows
+    /// Filters, search and totals apply to the root rows, which are not grouped.
+    /// Ignored with a data source
+    pub get_children: Option<GetChildrenFn<T>>,
+    /// Children of a row loaded when it is first expanded (used when `get_children` is not set)
+    pub load_children: Option<LoadChildrenFn<T>>,
     /// Whether selection is enabled
     pub selectable: Option<bool>,
     /// Whether multiple selection is allowed
@@ -68,39 +114,106 @@ pub struct UseTableParams<T: Clone + Hash + Eq + 'static> {
     pub select_cancelable: Option<bool>,
     /// Initial selected IDs
     pub default_select: Option<Vec<String>>,
-    /// Selection change callback
-    pub on_select: Option<OnSelectCallback>,
+    /// Called before the selection changes (prevent_default vetoes the change)
+    pub on_before_select: Option<OnSelectChangeFn>,
+    /// Called with the selected data after the selection changed
+    pub on_select: Option<OnSelectCallback<T>>,
+}
+
+// Written by hand so that `T` needn't be `Default`
+impl<T: Clone + Hash + Eq + 'static> Default for UseTableParams<T> {
+    fn default() -> Self {
+        Self {
+            data: Vec::new(),
+            render_map: RenderMap::new(),
+            get_data_id: None,
+            default_pagination: None,
+            default_focus: None,
+            default_sort: None,
+            default_column_order: None,
+            default_filter: None,
+            search: None,
+            data_source: None,
+            column_virtualization: None,
+            total_scope: None,
+            group_by: None,
+            get_children: None,
+            load_children: None,
+            selectable: None,
+            select_many: None,
+            select_cancelable: None,
+            default_select: None,
+            on_before_select: None,
+            on_select: None,
+        }
+    }
 }
 
 /// State props for the TableView component
+#[derive(Clone)]
 pub struct TableViewStateProps<T: Clone + Hash + Eq + 'static> {
     /// Virtualized data for rendering
     pub virtualized_data: Vec<DataWithId<T>>,
-    /// Total data length
+    /// Total data length (reported by the data source when set)
     pub data_length: usize,
+    /// Whether the data source is fetching the current page
+    pub is_loading: bool,
     /// Whether multiple selection is enabled
     pub select_many: bool,
     /// Column configuration map
     pub column_option_map: ColumnOptionMap<T>,
     /// Column width management
     pub column_widthes: UseColumnWidthsResult,
+    /// Column order management
+    pub column_order: UseColumnOrderResult,
     /// Virtual scroll management
     pub virtual_scroll: crate::components::render::use_virtual_scroll::UseVirtualScrollResult<DataWithId<T>>,
+    /// Column-axis virtualization
+    pub column_virtualization: UseColumnVirtualizationResult,
+    /// Totals of the columns with `total` set, by key
+    pub totals: HashMap<String, f64>,
+    /// Row grouping management
+    pub row_grouping: UseRowGroupingResult,
+    /// Totals of the rows of each group
+    pub group_totals: GroupTotals,
+    /// Tree data management
+    pub tree_data: UseTreeDataResult<T>,
+    /// Detail panel expansion management
+    pub row_expansion: UseRowExpansionResult,
     /// Sort management
     pub sort: UseSortResult<String, T>,
+    /// Column filter management
+    pub filter: UseFilterResult<String>,
+    /// Distinct cell texts of a column over unfiltered data (for set filters)
+    pub get_filter_options: ColumnValueArrayFn,
+    /// Global quick search (when enabled)
+    pub search: Option<UseTableSearchResult>,
     /// Combined pagination state and functions
     pub pagination: CombinedPagination,
     /// Combined focus state and functions
     pub focus: CombinedFocus,
     /// Selection management
     pub select: UseSelectResult,
+    /// Currently focused data item
+    pub focused_data: Option<DataWithId<T>>,
+    /// Extend selection from the anchor (or focused row) to the given ID
+    pub extend_select_to: Rc<RefCell<dyn FnMut(String)>>,
     /// Function to get render index from ID
     pub get_render_index_from_id: Rc<dyn Fn(Option<String>) -> Option<usize>>,
     /// Function to get ID from render index
     pub get_id_from_render_index: Rc<dyn Fn(Option<usize>) -> Option<String>>,
 }
 
+// Hook closures are rebuilt on every render of the owning component,
+// so state props never compare equal and TableView re-renders along with it
+impl<T: Clone + Hash + Eq + 'static> PartialEq for TableViewStateProps<T> {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
 /// Combined pagination state and functions
+#[derive(Clone)]
 pub struct CombinedPagination {
     /// Pagination state
     pub state: UsePaginationResult,
@@ -123,13 +236,18 @@ pub type SetByLocalIndexFn = Rc<RefCell<dyn FnMut(
     Option<crate::components::table_view::use_focus_fn::FocusByLocalIndexOptions>
 )>>;
 
+#[derive(Clone)]
 pub struct CombinedFocus {
     /// Current focused ID with fallback
     pub id: Option<String>,
+    /// Current focused column key
+    pub column: Option<String>,
     /// Whether focus is active
     pub active: bool,
     /// Set focus ID
     pub set_id: Rc<RefCell<dyn FnMut(Option<String>)>>,
+    /// Set focused column key
+    pub set_column: Rc<RefCell<dyn FnMut(Option<String>)>>,
     /// Set active state
     pub set_active: Rc<RefCell<dyn FnMut(bool)>>,
     /// Set scroll container reference
@@ -140,6 +258,8 @@ pub struct CombinedFocus {
     pub set_by_id: SetByIdFn,
     /// Set focus by local index
     pub set_by_local_index: SetByLocalIndexFn,
+    /// Set focus by render index
+    pub set_by_render_index: FocusByRenderIndexFn,
 }
 
 /// Props structure for TableView component
@@ -186,6 +306,34 @@ pub struct UseTableResult<T: Clone + Hash + Eq + 'static> {
     pub select_by_ids: Rc<RefCell<dyn FnMut(Vec<String>)>>,
     /// Current sort orders
     pub sort_orders: Vec<(String, Order)>,
+    /// Current column filters
+    pub filter_map: HashMap<String, FilterValue>,
+    /// Set (`Some`) or clear (`None`) the filter of a column
+    pub set_filter: SetFilterFn<String>,
+    /// Clear every column filter
+    pub clear_filters: Rc<RefCell<dyn FnMut()>>,
+    /// Global quick search (when enabled)
+    pub search: Option<UseTableSearchResult>,
+    /// Whether the data source is fetching the current page
+    pub is_loading: bool,
+    /// Fetch the current page from the data source again
+    pub refresh: Rc<dyn Fn()>,
+    /// Keys of every column in display order
+    pub column_order: Vec<String>,
+    /// Move a column to an index of `column_order`, keeping row headers grouped
+    pub move_column: Rc<RefCell<dyn FnMut(String, usize)>>,
+    /// Hide, show, toggle and reset columns at runtime
+    pub column_visibility: UseColumnVisibilityResult,
+    /// Totals of the columns with `total` set, by key
+    pub totals: HashMap<String, f64>,
+    /// Group rows by columns and expand or collapse the groups
+    pub row_grouping: UseRowGroupingResult,
+    /// Totals of the rows of each group, by group ID then column key
+    pub group_totals: GroupTotals,
+    /// Expand or collapse the rows of tree data
+    pub tree_data: UseTreeDataResult<T>,
+    /// Expand or collapse the detail panels of rows
+    pub row_expansion: UseRowExpansionResult,
     /// Column value extraction utilities
     pub get_column_value_to_render_indices: ColumnValueUtils,
 }
@@ -220,7 +368,7 @@ pub struct UseTableResult<T: Clone + Hash + Eq + 'static> {
 ///         }),
 ///         selectable: Some(true),
 ///         select_many: Some(false),
-///         // ... other params
+///         ..Default::default()
 ///     });
 ///     
 ///     rsx! {
@@ -251,11 +399,77 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
     
     // Get column options
     let column_option_map = get_column_option_map(&params.render_map);
+    let column_visibility = use_column_visibility(&column_option_map);
+    let column_option_map = apply_column_visibility(&column_option_map, &column_visibility.hidden_keys);
     let column_widthes = use_column_widths(column_option_map.clone());
+    let column_order = use_column_order({
+        let default_column_order = params.default_column_order.clone().unwrap_or_default();
+        move || default_column_order.clone()
+    }, &column_option_map);
+    let column_virtualization = use_column_virtualization(
+        params.column_virtualization.clone(),
+        &get_ordered_column_options(&column_option_map, &column_order.order),
+        &column_widthes.get,
+    );
+    
+    let is_remote = params.data_source.is_some();
+    
+    // Index of each item in the data (the first one for duplicates), from which
+    // row IDs and cell texts are computed whatever the filters and the order
+    let data_indices: HashMap<&T, usize> = params.data.iter()
+        .enumerate()
+        .rev()
+        .map(|(index, item)| (item, index))
+        .collect();
+    
+    // Set up filtering (applied before sorting)
+    let filter = use_filter({
+        let default_filter: HashMap<String, FilterValue> = params.default_filter.clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        move || default_filter.clone()
+    });
+    
+    let filtered_data = if is_remote {
+        Vec::new()
+    } else {
+        let custom_filter_map: HashMap<String, CustomFilterFn> = column_option_map.iter()
+            .filter_map(|(key, opt)| match &opt.filter {
+                Some(FilterKind::Custom(predicate)) => Some((key.clone(), predicate.clone())),
+                _ => None,
+            })
+            .collect();
+        
+        get_filtered(params.data.clone(), &filter.filter_map, &custom_filter_map, |key, item, index| {
+            column_option_map.get(key)
+                .map(|opt| get_cell_text(opt, item, &(get_data_id)(item, index), index))
+                .unwrap_or_default()
+        })
+    };
     
-    // Set up sorting
+    // Set up quick search (applied after column filters)
+    let search = use_table_search(params.search.clone().unwrap_or_default());
+    let search_query = (params.search.is_some() && !search.query.is_empty()).then(|| search.query.clone());
+    let search_scores: Option<HashMap<T, i64>> = search_query.as_ref().filter(|_| !is_remote).map(|search_query| {
+        filtered_data.iter()
+            .filter_map(|item| {
+                let index = data_indices.get(item).copied().unwrap_or_default();
+                let cell_texts = column_option_map.values()
+                    .filter(|opt| !opt.is_hidden)
+                    .map(|opt| get_cell_text(opt, item, &(get_data_id)(item, index), index));
+                get_row_score(cell_texts, search_query, search.fuzzy).map(|score| (item.clone(), score))
+            })
+            .collect()
+    });
+    let filtered_data: Vec<T> = match &search_scores {
+        Some(scores) => filtered_data.into_iter().filter(|item| scores.contains_key(item)).collect(),
+        None => filtered_data,
+    };
+    
+    // Set up sorting (a data source sorts on its side, so only the order state is used)
     let sort = use_sort(
-        params.data.clone(),
+        filtered_data.clone(),
         {
             let default_sort = params.default_sort.clone().unwrap_or_else(|| {
                 // Create default sort from column options with init_sort_order
@@ -298,19 +512,132 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
     // }).collect();
     
     // Sort the data
-    let sorted_data: Vec<DataWithId<T>> = {
-        let sorted_items = (sort.get_sorted_by_indices)(params.data.clone());
+    let local_sorted_data: Vec<DataWithId<T>> = {
+        let mut sorted_items = (sort.get_sorted_by_indices)(filtered_data.clone());
+        // Fuzzy search ranks rows by match quality (stable, so the sort order breaks ties)
+        if let (Some(scores), true) = (&search_scores, search.fuzzy) {
+            sorted_items.sort_by_key(|item| std::cmp::Reverse(scores.get(item).copied().unwrap_or(0)));
+        }
         sorted_items.iter().enumerate().map(|(render_index, item)| {
+            let data_index = data_indices.get(item).copied().unwrap_or(render_index);
             DataWithId {
                 get: item.clone(),
-                id: (get_data_id)(item, render_index), // Use sorted index for ID generation
-                data_index: params.data.iter().position(|x| x == item).unwrap_or(render_index),
+                id: (get_data_id)(item, data_index),
+                data_index,
                 render_index: Some(render_index),
                 local_index: None,
+                depth: 0,
+                group_path: Vec::new(),
+                group: None,
+                tree: None,
             }
         }).collect()
     };
     
+    // Totals over every filtered row are taken before grouping leaves out collapsed rows
+    let total_scope = params.total_scope.unwrap_or_default();
+    let filtered_totals = (total_scope == TotalScope::Filtered && !is_remote)
+        .then(|| get_column_totals(&column_option_map, &local_sorted_data));
+    
+    // Set up row grouping (a data source pages flat rows, so it is not grouped)
+    let row_grouping = use_row_grouping({
+        let group_by = params.group_by.clone().unwrap_or_default();
+        move || group_by.clone()
+    });
+    
+    // Set up tree data (a data source pages flat rows, so it is not a tree)
+    let tree_data = use_tree_data(
+        (!is_remote && (params.get_children.is_some() || params.load_children.is_some())).then(|| TreeDataOptions {
+            get_children: params.get_children.clone(),
+            load_children: params.load_children.clone(),
+        }),
+        local_sorted_data,
+        {
+            let get_sorted_by_indices = sort.get_sorted_by_indices.clone();
+            move |items: Vec<T>| (get_sorted_by_indices)(items)
+        },
+        {
+            let get_data_id = get_data_id.clone();
+            move |item: &T, index: usize| (get_data_id)(item, index)
+        },
+    );
+    
+    // Set up detail panels (TableView renders them with `render_detail`)
+    let row_expansion = use_row_expansion();
+    
+    // Rows in display order: tree rows, or grouped rows with collapsed groups left out
+    // Pagination and render indices use this flattened order
+    let (local_rows, local_group_totals) = if !is_remote && !tree_data.is_enabled && !row_grouping.group_by.is_empty() {
+        get_grouped_rows(
+            tree_data.rows.clone(),
+            &row_grouping.group_by,
+            &column_option_map,
+            |group_id| row_grouping.is_expanded(group_id),
+        )
+    } else {
+        (tree_data.rows.clone(), GroupTotals::new())
+    };
+    
+    // Set up focus
+    let focus = use_focus(params.default_focus.map(FocusInit::Value));
+    
+    // A data source pages by this cursor instead of the focused row, which may not be loaded
+    let remote_cursor = use_signal(|| 0usize);
+    let pending_focus_render_index = use_signal(|| None::<usize>);
+    
+    // Set up pagination
+    let pagination = use_pagination(UsePaginationParams {
+        init: default_pagination.limit.unwrap_or(10),
+        disabled: if default_pagination.limit.is_none() { 
+            Some(if is_remote { usize::MAX } else { local_rows.len() })
+        } else { 
+            None 
+        },
+        focused_render_index: if is_remote {
+            Some(*remote_cursor.read())
+        } else {
+            focus.id.as_ref().and_then(|id| local_rows.iter().find(|item| item.id == *id)?.render_index)
+        },
+    });
+    
+    // Fetch the current page from the data source
+    let remote = use_data_source(params.data_source.clone(), DataQuery {
+        sort: sort.order_entries.clone(),
+        filters: {
+            let mut filters: Vec<(String, FilterValue)> = filter.filter_map.clone().into_iter().collect();
+            filters.sort_by(|(prev, _), (next, _)| prev.cmp(next));
+            filters
+        },
+        search: search_query,
+        offset: pagination.offset,
+        limit: default_pagination.limit.is_some().then_some(pagination.limit),
+    });
+    
+    let (sorted_data, data_length, group_totals) = match (&remote.page, &remote.page_query) {
+        (Some(page), Some(page_query)) if is_remote => {
+            let sorted_data: Vec<DataWithId<T>> = page.items.iter().enumerate().map(|(index, item)| {
+                let render_index = page_query.offset + index;
+                DataWithId {
+                    get: item.clone(),
+                    id: (get_data_id)(item, render_index),
+                    data_index: render_index,
+                    render_index: Some(render_index),
+                    local_index: None,
+                    depth: 0,
+                    group_path: Vec::new(),
+                    group: None,
+                    tree: None,
+                }
+            }).collect();
+            (sorted_data, page.total, GroupTotals::new())
+        }
+        _ if is_remote => (Vec::new(), 0, GroupTotals::new()),
+        _ => {
+            let data_length = local_rows.len();
+            (local_rows, data_length, local_group_totals)
+        }
+    };
+    
     // Helper functions for ID/index mapping
     let get_render_index_from_id = {
         let sorted_data = sorted_data.clone();
@@ -323,9 +650,11 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
     
     let get_id_from_render_index = {
         let sorted_data = sorted_data.clone();
+        // Only a page of a data source is loaded
+        let first_render_index = sorted_data.first().and_then(|item| item.render_index).unwrap_or(0);
         Rc::new(move |render_index: Option<usize>| -> Option<String> {
             render_index.and_then(|index| {
-                sorted_data.get(index).map(|item| item.id.clone())
+                sorted_data.get(index.checked_sub(first_render_index)?).map(|item| item.id.clone())
             })
         })
     };
@@ -339,33 +668,52 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
         select_many,
         selectable,
         select_cancelable,
-        params.on_select.map(|_| {
-            Rc::new(move |event: &crate::components::table_view::use_select::SelectEvent| {
-                // Convert SelectEvent to just IDs for the callback
-                event.apply_callback();
-            }) as Rc<dyn Fn(&crate::components::table_view::use_select::SelectEvent)>
-        })
+        params.on_before_select,
+        params.on_select.map(|on_select| {
+            let sorted_data = sorted_data.clone();
+            Rc::new(move |ids: &[String]| {
+                let selected_data = ids.iter()
+                    .filter_map(|id| sorted_data.iter().find(|item| item.id == *id))
+                    .cloned()
+                    .collect();
+                on_select.borrow_mut()(selected_data);
+            }) as Rc<dyn Fn(&[String])>
+        }),
+        SelectRenderOrder {
+            get_render_index_from_id: get_render_index_from_id.clone(),
+            get_id_from_render_index: get_id_from_render_index.clone(),
+            max_render_index: data_length,
+            is_selectable: Rc::new(|id: &str| !is_group_id(id)),
+        },
     );
     
-    // Set up focus
-    let focus = use_focus(params.default_focus.map(FocusInit::Value));
     let focused_render_index = (get_render_index_from_id)(focus.id.clone());
     
-    // Set up pagination
-    let pagination = use_pagination(UsePaginationParams {
-        init: default_pagination.limit.unwrap_or(10),
-        disabled: if default_pagination.limit.is_none() { 
-            Some(sorted_data.len()) 
-        } else { 
-            None 
-        },
-        focused_render_index,
-    });
+    // Extend selection from the anchor, which is fixed to the focused row on first extension
+    let extend_select_to = {
+        let focus_id = focus.id.clone();
+        let anchor_id = select.anchor_id.clone();
+        let set_anchor_id = select.set_anchor_id.clone();
+        let select_range = select.select_range.clone();
+        
+        Rc::new(RefCell::new(move |target_id: String| {
+            let anchor_id = anchor_id.clone()
+                .or_else(|| focus_id.clone())
+                .unwrap_or_else(|| target_id.clone());
+            set_anchor_id.borrow_mut()(Some(anchor_id.clone()));
+            select_range.borrow_mut()(anchor_id, target_id);
+        }))
+    };
     
-    // Create paginated data
+    // Create paginated data (a data source already returns a single page)
     let paginated_data: Vec<DataWithId<T>> = {
-        let start = pagination.offset;
-        let end = (start + pagination.limit).min(sorted_data.len());
+        let (start, end) = if is_remote {
+            (0, sorted_data.len())
+        } else {
+            // The page may be past the end for a moment, e.g. right after collapsing groups
+            let start = pagination.offset.min(sorted_data.len());
+            (start, start.saturating_add(pagination.limit).min(sorted_data.len()))
+        };
         sorted_data[start..end].iter().enumerate().map(|(local_index, item)| {
             let mut paginated_item = item.clone();
             paginated_item.local_index = Some(local_index);
@@ -373,11 +721,22 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
         }).collect()
     };
     
-    // Set up virtual scrolling
+    // Aggregate the totals (a data source only has the current page)
+    let totals = filtered_totals.unwrap_or_else(|| {
+        let page_rows: Vec<DataWithId<T>> = paginated_data.iter()
+            // Children of tree data are left out like the rows filtered out of the roots
+            .filter(|row| row.group.is_none() && row.tree.as_ref().is_none_or(|tree| tree.parent_id.is_none()))
+            .cloned()
+            .collect();
+        get_column_totals(&column_option_map, &page_rows)
+    });
+    
+    // Set up virtual scrolling (row heights follow the row IDs across sorting and paging)
     let virtual_scroll = use_virtual_scroll(UseVirtualScrollParams {
         default_content_height_px: 35.0,
         content_length: paginated_data.len(),
         over_scan: Some(4),
+        content_ids: Some(paginated_data.iter().map(|item| item.id.clone()).collect()),
     });
     
     let virtualized_data = (virtual_scroll.get_virtualized)(&paginated_data);
@@ -414,16 +773,50 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
                 }
             })
         },
-        max_render_index: sorted_data.len(),
+        max_render_index: data_length,
         max_local_index: paginated_data.len(),
         select_many,
     });
     
+    // A data source moves the page cursor, focusing the row once its page is loaded
+    let set_by_render_index: FocusByRenderIndexFn = if is_remote {
+        let set_by_render_index = focus_fns.set_by_render_index.clone();
+        let get_id_from_render_index = get_id_from_render_index.clone();
+        let mut remote_cursor = remote_cursor;
+        let mut pending_focus_render_index = pending_focus_render_index;
+        
+        Rc::new(RefCell::new(move |set_state_action: SetStateAction<usize>, options: Option<FocusByRenderIndexOptions>| {
+            let prev = focused_render_index.unwrap_or_else(|| *remote_cursor.peek());
+            let next = SetterUtils::to_value(set_state_action, prev).min(data_length.saturating_sub(1));
+            remote_cursor.set(next);
+            if (get_id_from_render_index)(Some(next)).is_some() {
+                set_by_render_index.borrow_mut()(SetStateAction::Value(next), options);
+            } else {
+                pending_focus_render_index.set(Some(next));
+            }
+        }))
+    } else {
+        focus_fns.set_by_render_index.clone()
+    };
+    
+    let pending_focus_id = pending_focus_render_index.read()
+        .and_then(|render_index| (get_id_from_render_index)(Some(render_index)));
+    use_effect(use_reactive((&pending_focus_id,), {
+        let set_id = focus.set_id.clone();
+        let mut pending_focus_render_index = pending_focus_render_index;
+        move |(pending_focus_id,)| {
+            if pending_focus_id.is_some() {
+                set_id.borrow_mut()(pending_focus_id);
+                pending_focus_render_index.set(None);
+            }
+        }
+    }));
+    
     // Set up pagination functions
     let pagination_fns = use_pagination_fn(UsePaginationFnParams {
         pagination: pagination.clone(),
         auto_limit: default_pagination.auto_limit.unwrap_or(true),
-        set_focus_by_render_index: focus_fns.set_by_render_index.clone(),
+        set_focus_by_render_index: set_by_render_index.clone(),
     });
     
     // Helper function to get data from IDs
@@ -506,9 +899,29 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
         },
     };
     
+    let get_filter_options = {
+        let data = params.data.clone();
+        let column_option_map = column_option_map.clone();
+        let get_data_id = get_data_id.clone();
+        Rc::new(move |column_key: &str| -> Vec<String> {
+            let Some(opt) = column_option_map.get(column_key) else {
+                return Vec::new();
+            };
+            get_distinct_values(data.iter().enumerate().map(|(index, item)| {
+                get_cell_text(opt, item, &(get_data_id)(item, index), index)
+            }))
+        })
+    };
+    
     // Initialize function
     let init_fn = {
         let column_widthes_init = column_widthes.init.clone();
+        let column_order_init = column_order.init.clone();
+        let column_visibility_reset = column_visibility.reset.clone();
+        let row_grouping_init = row_grouping.init.clone();
+        let tree_data_collapse_all = tree_data.collapse_all.clone();
+        let row_expansion_collapse_all = row_expansion.collapse_all.clone();
+        let filter_init = filter.init.clone();
         // let sort_init = sort.init.clone();
         let pagination_init = pagination.init.clone();
         let focus_init = focus.init.clone();
@@ -516,6 +929,12 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
         
         Rc::new(RefCell::new(move || {
             (column_widthes_init.borrow_mut())();
+            (column_order_init.borrow_mut())();
+            (column_visibility_reset.borrow_mut())();
+            (row_grouping_init.borrow_mut())();
+            (tree_data_collapse_all.borrow_mut())();
+            (row_expansion_collapse_all.borrow_mut())();
+            (filter_init.borrow_mut())();
             // For sort init, it's Rc<dyn FnMut()> so we can't borrow_mut, just call it
             // This suggests the type definitions may be inconsistent
             // Let's skip calling the sort init for now since it's causing type issues
@@ -527,37 +946,56 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
     
     // Get selected and focused data
     let selected_data = get_from_ids(&select.ids.iter().map(|id| Some(id.clone())).collect::<Vec<_>>());
-    let focused_data = get_from_ids(&[focus.id.clone()]).into_iter().next();
+    let focused_data = get_from_ids(std::slice::from_ref(&focus.id)).into_iter().next();
     
     // Clone values that will be needed after move
     let select_set_ids = select.set_ids.clone();
     let sort_order_entries = sort.order_entries.clone();
+    let filter_map = filter.filter_map.clone();
+    let set_filter = filter.set_filter.clone();
+    let clear_filters = filter.clear_all.clone();
     
     UseTableResult {
         props: TableViewProps {
             state_props: TableViewStateProps {
                 virtualized_data,
-                data_length: params.data.len(),
+                data_length,
+                is_loading: remote.is_loading,
                 select_many,
                 column_option_map,
                 column_widthes,
+                column_order: column_order.clone(),
                 virtual_scroll,
+                column_virtualization,
+                totals: totals.clone(),
+                row_grouping: row_grouping.clone(),
+                group_totals: group_totals.clone(),
+                tree_data: tree_data.clone(),
+                row_expansion: row_expansion.clone(),
                 sort,
+                filter,
+                get_filter_options,
+                search: params.search.is_some().then(|| search.clone()),
                 pagination: CombinedPagination {
                     state: pagination,
                     functions: pagination_fns,
                 },
                 focus: CombinedFocus {
                     id: focus_fns.id.clone(),
+                    column: focus.column.clone(),
                     active: focus.active,
                     set_id: focus.set_id.clone(),
+                    set_column: focus.set_column.clone(),
                     set_active: focus.set_active.clone(),
                     set_scroll_ref: focus_fns.set_scroll_ref.clone(),
                     set_content_refs: focus.set_content_refs.clone(),
                     set_by_id: focus_fns.set_by_id.clone(),
                     set_by_local_index: focus_fns.set_by_local_index.clone(),
+                    set_by_render_index: set_by_render_index.clone(),
                 },
                 select,
+                focused_data: focused_data.clone(),
+                extend_select_to,
                 get_render_index_from_id,
                 get_id_from_render_index,
             },
@@ -566,7 +1004,7 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
         selected_data,
         focused_data,
         focus_by_id: focus_fns.set_by_id,
-        focus_by_render_index: focus_fns.set_by_render_index,
+        focus_by_render_index: set_by_render_index,
         keep_select_by_removed_ids: keep_select_by_removed_ids_fn,
         select_by_ids: {
             Rc::new(RefCell::new(move |ids: Vec<String>| {
@@ -575,12 +1013,27 @@ pub fn use_table<T: Clone + Hash + Eq + 'static>(params: UseTableParams<T>) -> U
         },
         sort_orders: sort_order_entries.iter().map(|(key, order)| (key.clone(), order.clone())).collect(),
         get_column_value_to_render_indices: column_value_utils,
+        filter_map,
+        set_filter,
+        clear_filters,
+        search: params.search.is_some().then_some(search),
+        is_loading: remote.is_loading,
+        refresh: remote.refresh,
+        column_order: column_order.order,
+        move_column: column_order.move_column,
+        column_visibility,
+        totals,
+        row_grouping,
+        group_totals,
+        tree_data,
+        row_expansion,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::render_until_idle;
     
     #[derive(Clone, Debug, PartialEq, Eq, Hash)]
     struct TestData {
@@ -601,6 +1054,10 @@ mod tests {
             data_index: 0,
             render_index: Some(0),
             local_index: Some(0),
+            depth: 0,
+            group_path: Vec::new(),
+            group: None,
+            tree: None,
         };
         
         assert_eq!(data.id, "test-1");
@@ -638,13 +1095,9 @@ mod tests {
                     limit: Some(10),
                     auto_limit: Some(false),
                 }),
-                default_focus: None,
-                default_sort: None,
                 selectable: Some(true),
                 select_many: Some(false),
-                select_cancelable: None,
-                default_select: None,
-                on_select: None,
+                ..Default::default()
             });
             
             // Test basic structure
@@ -670,15 +1123,12 @@ mod tests {
             let table = use_table(UseTableParams {
                 data: test_data,
                 render_map: create_test_render_map(),
-                get_data_id: None, // Use default
-                default_pagination: None, // Use default
                 default_focus: Some("item-0".to_string()),
-                default_sort: None,
                 selectable: Some(true),
                 select_many: Some(true),
                 select_cancelable: Some(true),
                 default_select: Some(vec!["item-0".to_string()]),
-                on_select: None,
+                ..Default::default()
             });
             
             // Test selection configuration
@@ -690,4 +1140,609 @@ mod tests {
         
         dom.rebuild_to_vec();
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_use_table_on_select_receives_data() {
+        use dioxus::prelude::*;
+        
+        let mut dom = VirtualDom::new(|| {
+            let received = use_hook(|| Rc::new(RefCell::new(Vec::<DataWithId<TestData>>::new())));
+            
+            let table = use_table(UseTableParams {
+                data: vec![
+                    TestData { id: 1, name: "Alice".to_string(), value: 100 },
+                    TestData { id: 2, name: "Bob".to_string(), value: 200 },
+                ],
+                render_map: create_test_render_map(),
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                selectable: Some(true),
+                select_many: Some(true),
+                on_before_select: Some(Rc::new(|event| {
+                    // Veto selecting Alice
+                    if event.added_ids.contains(&"item-1".to_string()) {
+                        event.prevent_default();
+                    }
+                })),
+                on_select: Some(Rc::new(RefCell::new({
+                    let received = received.clone();
+                    move |data: Vec<DataWithId<TestData>>| *received.borrow_mut() = data
+                }))),
+                ..Default::default()
+            });
+            
+            table.select_by_ids.borrow_mut()(vec!["item-1".to_string()]);
+            assert!(received.borrow().is_empty());
+            
+            table.select_by_ids.borrow_mut()(vec!["item-2".to_string()]);
+            assert_eq!(received.borrow().len(), 1);
+            assert_eq!(received.borrow()[0].get.name, "Bob");
+            
+            rsx! { div {} }
+        });
+        
+        dom.rebuild_to_vec();
+    }
+    
+    #[test]
+    fn test_use_table_default_filter() {
+        use dioxus::prelude::*;
+        use crate::components::table_view::get_column_option_map::{ColumnOptionArgs, RenderMapValue, RenderOptions};
+        
+        let mut dom = VirtualDom::new(|| {
+            let mut render_map = create_test_render_map();
+            render_map.insert("value".to_string(), RenderMapValue::Options(ColumnOptionArgs {
+                label: "Value".to_string(),
+                value_mapper: Some(Rc::new(|raw: &dyn std::any::Any, _options: &RenderOptions<TestData>| {
+                    raw.downcast_ref::<TestData>().map(|data| data.value.to_string()).unwrap_or_default()
+                })),
+                filter: Some(FilterKind::NumberRange),
+                ..Default::default()
+            }));
+            
+            let table = use_table(UseTableParams {
+                data: vec![
+                    TestData { id: 1, name: "Alice".to_string(), value: 100 },
+                    TestData { id: 2, name: "Bob".to_string(), value: 200 },
+                    TestData { id: 3, name: "Carol".to_string(), value: 300 },
+                ],
+                render_map,
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                default_filter: Some(vec![(
+                    "value".to_string(),
+                    FilterValue::NumberRange { min: Some(150.0), max: None },
+                )]),
+                selectable: Some(true),
+                select_many: Some(true),
+                ..Default::default()
+            });
+            
+            let state_props = &table.props.state_props;
+            assert_eq!(state_props.data_length, 2);
+            assert_eq!(state_props.virtualized_data.iter().map(|row| row.id.as_str()).collect::<Vec<_>>(), vec!["item-2", "item-3"]);
+            assert_eq!((state_props.get_id_from_render_index)(Some(1)), Some("item-3".to_string()));
+            assert_eq!(table.filter_map.len(), 1);
+            assert_eq!((state_props.get_filter_options)("value"), vec!["100", "200", "300"]);
+            
+            rsx! { div {} }
+        });
+        
+        dom.rebuild_to_vec();
+    }
+    
+    #[test]
+    fn test_use_table_cell_texts_use_row_ids() {
+        use dioxus::prelude::*;
+        use crate::components::table_view::get_column_option_map::{ColumnOptionArgs, RenderMapValue, RenderOptions};
+        
+        let mut dom = VirtualDom::new(|| {
+            let mut render_map = create_test_render_map();
+            render_map.insert("value".to_string(), RenderMapValue::Options(ColumnOptionArgs {
+                label: "Value".to_string(),
+                value_mapper: Some(Rc::new(|raw: &dyn std::any::Any, _options: &RenderOptions<TestData>| {
+                    raw.downcast_ref::<TestData>().map(|data| data.value.to_string()).unwrap_or_default()
+                })),
+                filter: Some(FilterKind::NumberRange),
+                ..Default::default()
+            }));
+            // Cell text from the row ID, as rendered
+            render_map.insert("row".to_string(), RenderMapValue::Options(ColumnOptionArgs {
+                label: "Row".to_string(),
+                value_mapper: Some(Rc::new(|_raw: &dyn std::any::Any, options: &RenderOptions<TestData>| {
+                    format!("row-{}", options.id)
+                })),
+                ..Default::default()
+            }));
+            
+            let table = use_table(UseTableParams {
+                data: vec![
+                    TestData { id: 1, name: "Alice".to_string(), value: 100 },
+                    TestData { id: 2, name: "Bob".to_string(), value: 200 },
+                    TestData { id: 3, name: "Carol".to_string(), value: 300 },
+                ],
+                render_map,
+                default_filter: Some(vec![(
+                    "value".to_string(),
+                    FilterValue::NumberRange { min: Some(150.0), max: None },
+                )]),
+                search: Some(TableSearchOptions {
+                    default_query: Some("row-2".to_string()),
+                    fuzzy: None,
+                    debounce_ms: None,
+                }),
+                ..Default::default()
+            });
+            
+            // IDs come from the index in the data, whether the rows before are filtered out or not
+            let state_props = &table.props.state_props;
+            assert_eq!(state_props.virtualized_data.iter().map(|row| row.id.as_str()).collect::<Vec<_>>(), vec!["2"]);
+            assert_eq!(state_props.virtualized_data[0].get.name, "Carol");
+            assert_eq!((state_props.get_filter_options)("row"), vec!["row-0", "row-1", "row-2"]);
+            
+            rsx! { div {} }
+        });
+        
+        dom.rebuild_to_vec();
+    }
+    
+    #[test]
+    fn test_use_table_fuzzy_search() {
+        use dioxus::prelude::*;
+        use crate::components::table_view::get_column_option_map::{ColumnOptionArgs, RenderMapValue, RenderOptions};
+        
+        let mut dom = VirtualDom::new(|| {
+            let mut render_map = create_test_render_map();
+            render_map.insert("name".to_string(), RenderMapValue::Options(ColumnOptionArgs {
+                label: "Name".to_string(),
+                value_mapper: Some(Rc::new(|raw: &dyn std::any::Any, _options: &RenderOptions<TestData>| {
+                    raw.downcast_ref::<TestData>().map(|data| data.name.clone()).unwrap_or_default()
+                })),
+                ..Default::default()
+            }));
+            
+            let table = use_table(UseTableParams {
+                data: vec![
+                    TestData { id: 1, name: "Bart".to_string(), value: 100 },
+                    TestData { id: 2, name: "Carol".to_string(), value: 200 },
+                    TestData { id: 3, name: "Bob".to_string(), value: 300 },
+                ],
+                render_map,
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                search: Some(TableSearchOptions {
+                    default_query: Some("BO".to_string()),
+                    fuzzy: Some(true),
+                    debounce_ms: None,
+                }),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+            
+            // "Bob" matches as a prefix, "Carol" only fuzzily and "Bart" not at all
+            let ids: Vec<&str> = table.props.state_props.virtualized_data.iter().map(|row| row.id.as_str()).collect();
+            assert_eq!(ids, vec!["item-3", "item-2"]);
+            assert_eq!(table.search.as_ref().map(|search| search.query.as_str()), Some("BO"));
+            
+            rsx! { div {} }
+        });
+        
+        dom.rebuild_to_vec();
+    }
+    
+    #[tokio::test]
+    async fn test_use_table_data_source() {
+        use dioxus::prelude::*;
+        use crate::components::table_view::data_source::InMemoryDataSource;
+        
+        type Recorded = Rc<RefCell<Vec<(usize, Vec<String>, bool)>>>;
+        
+        fn app(recorded: Recorded) -> Element {
+            let data: Vec<TestData> = (1..=5)
+                .map(|id| TestData { id, name: format!("name-{id}"), value: id * 100 })
+                .collect();
+            let source: Rc<dyn DataSource<TestData>> = use_hook(|| {
+                Rc::new(InMemoryDataSource::new(data, &create_test_render_map()))
+            });
+            
+            let table = use_table(UseTableParams {
+                render_map: create_test_render_map(),
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                default_pagination: Some(DefaultPagination {
+                    limit: Some(2),
+                    auto_limit: Some(false),
+                }),
+                data_source: Some(source),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+            
+            let state_props = &table.props.state_props;
+            recorded.borrow_mut().push((
+                state_props.data_length,
+                state_props.virtualized_data.iter().map(|row| row.id.clone()).collect(),
+                table.is_loading,
+            ));
+            
+            rsx! { div {} }
+        }
+        
+        let recorded: Recorded = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(app, recorded.clone());
+        dom.rebuild_in_place();
+        render_until_idle(&mut dom).await;
+        
+        let recorded = recorded.borrow();
+        assert_eq!(recorded.first(), Some(&(0, Vec::new(), true)));
+        // The total comes from the source while only the first page is held
+        assert_eq!(recorded.last(), Some(&(5, vec!["item-1".to_string(), "item-2".to_string()], false)));
+    }
+    
+    #[tokio::test]
+    async fn test_use_table_data_source_without_pagination() {
+        use dioxus::prelude::*;
+        use crate::components::table_view::data_source::{FetchFuture, InMemoryDataSource};
+        
+        struct RecordingDataSource {
+            inner: InMemoryDataSource<TestData>,
+            queries: Rc<RefCell<Vec<DataQuery>>>,
+        }
+        
+        impl DataSource<TestData> for RecordingDataSource {
+            fn fetch(&self, query: DataQuery) -> FetchFuture<TestData> {
+                self.queries.borrow_mut().push(query.clone());
+                self.inner.fetch(query)
+            }
+        }
+        
+        type Recorded = Rc<RefCell<Vec<DataQuery>>>;
+        
+        fn app(queries: Recorded) -> Element {
+            let data: Vec<TestData> = (1..=5)
+                .map(|id| TestData { id, name: format!("name-{id}"), value: id * 100 })
+                .collect();
+            let source: Rc<dyn DataSource<TestData>> = use_hook(|| {
+                Rc::new(RecordingDataSource {
+                    inner: InMemoryDataSource::new(data, &create_test_render_map()),
+                    queries,
+                })
+            });
+            
+            let table = use_table(UseTableParams {
+                render_map: create_test_render_map(),
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                default_pagination: Some(DefaultPagination { limit: None, auto_limit: None }),
+                data_source: Some(source),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+            assert!(table.is_loading || table.props.state_props.data_length == 5);
+            
+            rsx! { div {} }
+        }
+        
+        let queries: Recorded = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(app, queries.clone());
+        dom.rebuild_in_place();
+        render_until_idle(&mut dom).await;
+        
+        // Every row is requested instead of a page of `usize::MAX` rows
+        let queries = queries.borrow();
+        assert!(!queries.is_empty());
+        assert!(queries.iter().all(|query| query.offset == 0 && query.limit.is_none()));
+    }
+    
+    #[tokio::test]
+    async fn test_use_table_move_column() {
+        use dioxus::prelude::*;
+        use crate::components::table_view::get_column_option_map::{ColumnOptionArgs, RenderMapValue};
+        
+        type Recorded = Rc<RefCell<Vec<Vec<String>>>>;
+        
+        fn app(recorded: Recorded) -> Element {
+            let mut render_map = create_test_render_map();
+            for key in ["a", "b", "c"] {
+                render_map.insert(key.to_string(), RenderMapValue::Options(ColumnOptionArgs {
+                    label: key.to_uppercase(),
+                    ..Default::default()
+                }));
+            }
+            
+            let table = use_table(UseTableParams {
+                data: vec![TestData { id: 1, name: "Alice".to_string(), value: 100 }],
+                render_map,
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                default_column_order: Some(vec!["c".to_string()]),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+            
+            let move_column = table.move_column.clone();
+            use_effect(move || {
+                move_column.borrow_mut()("a".to_string(), 0);
+            });
+            recorded.borrow_mut().push(table.column_order.clone());
+            
+            rsx! { div {} }
+        }
+        
+        let recorded: Recorded = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(app, recorded.clone());
+        dom.rebuild_in_place();
+        // The effect moves the column, then the table renders again
+        render_until_idle(&mut dom).await;
+        
+        let recorded = recorded.borrow();
+        // Columns left out of the default order follow in key order
+        assert_eq!(recorded.first(), Some(&vec!["c".to_string(), "a".to_string(), "b".to_string()]));
+        assert_eq!(recorded.last(), Some(&vec!["a".to_string(), "c".to_string(), "b".to_string()]));
+    }
+    
+    #[test]
+    fn test_use_table_totals() {
+        use dioxus::prelude::*;
+        use crate::components::table_view::get_column_option_map::{ColumnOptionArgs, RenderMapValue};
+        
+        fn app(total_scope: TotalScope) -> Element {
+            let mut render_map = create_test_render_map();
+            render_map.insert("value".to_string(), RenderMapValue::Options(ColumnOptionArgs {
+                label: "Value".to_string(),
+                total: Some(true),
+                number_mapper: Some(Rc::new(|item: &TestData| Some(item.value as f64))),
+                ..Default::default()
+            }));
+            
+            let table = use_table(UseTableParams {
+                data: (1..=3).map(|id| TestData { id, name: format!("name-{id}"), value: id * 100 }).collect(),
+                render_map,
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                default_pagination: Some(DefaultPagination {
+                    limit: Some(2),
+                    auto_limit: Some(false),
+                }),
+                total_scope: Some(total_scope),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+            
+            let expected = match total_scope {
+                TotalScope::Filtered => 600.0,
+                TotalScope::Page => 300.0,
+            };
+            assert_eq!(table.totals.get("value"), Some(&expected));
+            assert_eq!(table.props.state_props.totals, table.totals);
+            
+            rsx! { div {} }
+        }
+        
+        for total_scope in [TotalScope::Filtered, TotalScope::Page] {
+            VirtualDom::new_with_props(app, total_scope).rebuild_to_vec();
+        }
+    }
+    
+    #[test]
+    fn test_use_table_group_by() {
+        use dioxus::prelude::*;
+        use crate::components::table_view::get_column_option_map::{ColumnOptionArgs, RenderMapValue, RenderOptions};
+        use crate::components::table_view::use_row_grouping::get_group_id;
+        
+        let mut dom = VirtualDom::new(|| {
+            let mut render_map = create_test_render_map();
+            render_map.insert("name".to_string(), RenderMapValue::Options(ColumnOptionArgs {
+                label: "Name".to_string(),
+                value_mapper: Some(Rc::new(|raw: &dyn std::any::Any, _options: &RenderOptions<TestData>| {
+                    raw.downcast_ref::<TestData>().map(|data| data.name.clone()).unwrap_or_default()
+                })),
+                ..Default::default()
+            }));
+            
+            let table = use_table(UseTableParams {
+                data: vec![
+                    TestData { id: 1, name: "a".to_string(), value: 100 },
+                    TestData { id: 2, name: "b".to_string(), value: 200 },
+                    TestData { id: 3, name: "a".to_string(), value: 300 },
+                ],
+                render_map,
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                group_by: Some(vec!["name".to_string()]),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+            
+            let state_props = &table.props.state_props;
+            let rows: Vec<(String, usize)> = state_props.virtualized_data.iter().map(|row| (row.id.clone(), row.depth)).collect();
+            let group_a = get_group_id(&["a".to_string()]);
+            let group_b = get_group_id(&["b".to_string()]);
+            assert_eq!(rows, vec![
+                (group_a.clone(), 0),
+                ("item-1".to_string(), 1),
+                ("item-3".to_string(), 1),
+                (group_b.clone(), 0),
+                ("item-2".to_string(), 1),
+            ]);
+            assert_eq!(state_props.data_length, 5);
+            assert_eq!((state_props.get_render_index_from_id)(Some(group_b)), Some(3));
+            assert_eq!(state_props.virtualized_data[0].group.as_ref().map(|group| group.count), Some(2));
+            
+            rsx! { div {} }
+        });
+        
+        dom.rebuild_to_vec();
+    }
+    
+    #[tokio::test]
+    async fn test_use_table_load_children() {
+        use dioxus::prelude::*;
+        
+        type Recorded = Rc<RefCell<Vec<Vec<(String, usize)>>>>;
+        
+        fn app(recorded: Recorded) -> Element {
+            let table = use_table(UseTableParams {
+                data: vec![
+                    TestData { id: 1, name: "Alice".to_string(), value: 100 },
+                    TestData { id: 2, name: "Bob".to_string(), value: 200 },
+                ],
+                render_map: create_test_render_map(),
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                load_children: Some(Rc::new(|item: TestData| Box::pin(async move {
+                    (1..=2).map(|index| TestData {
+                        id: item.id * 10 + index,
+                        name: format!("{}-{index}", item.name),
+                        value: item.value + index,
+                    }).collect()
+                }))),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+            
+            let set_expanded = table.tree_data.set_expanded.clone();
+            use_effect(move || {
+                set_expanded.borrow_mut()("item-1".to_string(), true);
+            });
+            recorded.borrow_mut().push(table.props.state_props.virtualized_data.iter()
+                .map(|row| (row.id.clone(), row.depth))
+                .collect());
+            
+            rsx! { div {} }
+        }
+        
+        let recorded: Recorded = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(app, recorded.clone());
+        dom.rebuild_in_place();
+        // The effect expands the row, then its children are loaded
+        render_until_idle(&mut dom).await;
+        
+        let recorded = recorded.borrow();
+        assert_eq!(recorded.first(), Some(&vec![("item-1".to_string(), 0), ("item-2".to_string(), 0)]));
+        assert_eq!(recorded.last(), Some(&vec![
+            ("item-1".to_string(), 0),
+            ("item-1/item-11".to_string(), 1),
+            ("item-1/item-12".to_string(), 1),
+            ("item-2".to_string(), 0),
+        ]));
+    }
+    
+    fn create_name_render_map() -> RenderMap<TestData> {
+        use crate::components::table_view::get_column_option_map::{ColumnOptionArgs, RenderMapValue, RenderOptions};
+        
+        let mut render_map = create_test_render_map();
+        render_map.insert("name".to_string(), RenderMapValue::Options(ColumnOptionArgs {
+            label: "Name".to_string(),
+            value_mapper: Some(Rc::new(|raw: &dyn std::any::Any, _options: &RenderOptions<TestData>| {
+                raw.downcast_ref::<TestData>().map(|data| data.name.clone()).unwrap_or_default()
+            })),
+            ..Default::default()
+        }));
+        render_map
+    }
+    
+    #[tokio::test]
+    async fn test_use_table_collapse_groups_on_later_page() {
+        use dioxus::prelude::*;
+        
+        // (page, row IDs of the page)
+        type Recorded = Rc<RefCell<Vec<(usize, Vec<String>)>>>;
+        
+        fn app(recorded: Recorded) -> Element {
+            let table = use_table(UseTableParams {
+                data: (0..30).map(|id| TestData {
+                    id,
+                    name: if id % 2 == 0 { "a" } else { "b" }.to_string(),
+                    value: id,
+                }).collect(),
+                render_map: create_name_render_map(),
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                default_pagination: Some(DefaultPagination { limit: Some(10), auto_limit: None }),
+                default_focus: Some("item-25".to_string()),
+                group_by: Some(vec!["name".to_string()]),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+            
+            let collapse_all = table.row_grouping.collapse_all.clone();
+            use_effect(move || {
+                collapse_all.borrow_mut()();
+            });
+            let state_props = &table.props.state_props;
+            recorded.borrow_mut().push((
+                state_props.pagination.state.page,
+                state_props.virtualized_data.iter().map(|row| row.id.clone()).collect(),
+            ));
+            
+            rsx! { div {} }
+        }
+        
+        let recorded: Recorded = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(app, recorded.clone());
+        dom.rebuild_in_place();
+        // The effect collapses the groups, then the table renders again
+        render_until_idle(&mut dom).await;
+        
+        let recorded = recorded.borrow();
+        // Group "a" takes 16 rows, so item-25 is row 29 of the grouped rows
+        let (page, ids) = recorded.first().unwrap();
+        assert_eq!(*page, 2);
+        assert_eq!(ids.first().map(String::as_str), Some("item-7"));
+        assert!(ids.contains(&"item-25".to_string()));
+        // Only the two group rows are left, on the first page
+        let (page, ids) = recorded.last().unwrap();
+        assert_eq!(*page, 0);
+        assert_eq!(ids.len(), 2);
+    }
+    
+    #[tokio::test]
+    async fn test_use_table_focus_child_on_later_page() {
+        use dioxus::prelude::*;
+        
+        type Recorded = Rc<RefCell<Vec<(usize, Vec<String>)>>>;
+        
+        fn app(recorded: Recorded) -> Element {
+            let table = use_table(UseTableParams {
+                data: (1..=12).map(|id| TestData { id, name: format!("row-{id}"), value: id }).collect(),
+                render_map: create_test_render_map(),
+                get_data_id: Some(Rc::new(|item, _| format!("item-{}", item.id))),
+                default_pagination: Some(DefaultPagination { limit: Some(4), auto_limit: None }),
+                default_focus: Some("item-8/item-82".to_string()),
+                get_children: Some(Rc::new(|item: &TestData| match item.id {
+                    8 => (1..=2).map(|index| TestData { id: 80 + index, name: format!("child-{index}"), value: index }).collect(),
+                    _ => Vec::new(),
+                })),
+                selectable: Some(true),
+                select_many: Some(false),
+                ..Default::default()
+            });
+            
+            let set_expanded = table.tree_data.set_expanded.clone();
+            use_effect(move || {
+                set_expanded.borrow_mut()("item-8".to_string(), true);
+            });
+            let state_props = &table.props.state_props;
+            recorded.borrow_mut().push((
+                state_props.pagination.state.page,
+                state_props.virtualized_data.iter().map(|row| row.id.clone()).collect(),
+            ));
+            
+            rsx! { div {} }
+        }
+        
+        let recorded: Recorded = Rc::new(RefCell::new(Vec::new()));
+        let mut dom = VirtualDom::new_with_props(app, recorded.clone());
+        dom.rebuild_in_place();
+        // The effect expands the row, then the table renders again
+        render_until_idle(&mut dom).await;
+        
+        // The focused child is row 9 of the flattened tree
+        let recorded = recorded.borrow();
+        assert_eq!(recorded.last(), Some(&(2, vec![
+            "item-8/item-81".to_string(),
+            "item-8/item-82".to_string(),
+            "item-9".to_string(),
+            "item-10".to_string(),
+        ])));
+    }
+}
@@ -0,0 +1,241 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::function::signal::use_debounce::{use_debounce, DebounceProps};
+
+/// Options of the global quick search
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableSearchOptions {
+    /// Initial query
+    pub default_query: Option<String>,
+    /// Whether to match characters in order with gaps, ranking rows by match quality
+    /// Default is `false` (substring match)
+    pub fuzzy: Option<bool>,
+    /// Debounce delay of the input in milliseconds
+    /// Default is `200`
+    pub debounce_ms: Option<u32>,
+}
+
+/// Match of a query in a cell text
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextMatch {
+    /// Higher is better
+    pub score: i64,
+    /// Matched byte ranges of the text, in order and not overlapping
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Find a query in a text (case-insensitive)
+///
+/// Substring matches always win over fuzzy matches; earlier matches score higher.
+/// Returns `None` for an empty query.
+pub fn find_match(text: &str, query: &str, fuzzy: bool) -> Option<TextMatch> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return None;
+    }
+    // (byte range, lowercase char) of each char; multi-char lowercase keeps the first
+    let chars: Vec<(Range<usize>, char)> = text
+        .char_indices()
+        .map(|(index, ch)| (index..index + ch.len_utf8(), ch.to_lowercase().next().unwrap_or(ch)))
+        .collect();
+
+    let substring_start = (0..chars.len().saturating_sub(query.len() - 1))
+        .find(|start| chars[*start..*start + query.len()].iter().zip(&query).all(|((_, ch), q)| ch == q));
+    if let Some(start) = substring_start {
+        let end = start + query.len();
+        let score = if start == 0 && end == chars.len() {
+            2000
+        } else if start == 0 {
+            1500
+        } else {
+            1000 - start.min(500) as i64
+        };
+        let range = chars[start].0.start..chars[end - 1].0.end;
+        return Some(TextMatch { score, ranges: Vec::from([range]) });
+    }
+
+    if !fuzzy {
+        return None;
+    }
+
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut matched_indices: Vec<usize> = Vec::new();
+    let mut query_chars = query.iter().peekable();
+    for (index, (range, ch)) in chars.iter().enumerate() {
+        let Some(q) = query_chars.peek() else {
+            break;
+        };
+        if ch != *q {
+            continue;
+        }
+        query_chars.next();
+        match ranges.last_mut() {
+            Some(last) if last.end == range.start => last.end = range.end,
+            _ => ranges.push(range.clone()),
+        }
+        matched_indices.push(index);
+    }
+    if query_chars.peek().is_some() {
+        return None;
+    }
+
+    let gaps: usize = matched_indices.windows(2).map(|pair| pair[1] - pair[0] - 1).sum();
+    let score = (500 - (gaps + matched_indices[0]).min(499) as i64).max(1);
+    Some(TextMatch { score, ranges })
+}
+
+/// Best score of a row over its cell texts
+pub fn get_row_score(cell_texts: impl IntoIterator<Item = String>, query: &str, fuzzy: bool) -> Option<i64> {
+    cell_texts
+        .into_iter()
+        .filter_map(|text| find_match(&text, query, fuzzy))
+        .map(|text_match| text_match.score)
+        .max()
+}
+
+/// Split a text into (segment, is_highlighted) by matched ranges
+pub fn split_by_ranges(text: &str, ranges: &[Range<usize>]) -> Vec<(String, bool)> {
+    let mut segments = Vec::new();
+    let mut cursor = 0;
+    for range in ranges {
+        if range.start > cursor {
+            segments.push((text[cursor..range.start].to_string(), false));
+        }
+        segments.push((text[range.clone()].to_string(), true));
+        cursor = range.end;
+    }
+    if cursor < text.len() {
+        segments.push((text[cursor..].to_string(), false));
+    }
+    segments
+}
+
+#[derive(Clone)]
+pub struct UseTableSearchResult {
+    /// Query as typed
+    pub input: String,
+    /// Debounced query used for matching
+    pub query: String,
+    pub fuzzy: bool,
+    /// Set the input, applying it to the query after the debounce delay
+    pub set_query: Rc<RefCell<dyn FnMut(String)>>,
+    /// Clear the input and the query immediately
+    pub clear: Rc<RefCell<dyn FnMut()>>,
+}
+
+impl UseTableSearchResult {
+    /// Match of the current query in a cell text
+    pub fn find(&self, text: &str) -> Option<TextMatch> {
+        find_match(text, &self.query, self.fuzzy)
+    }
+}
+
+/// Hook for the global quick search across all columns
+pub fn use_table_search(options: TableSearchOptions) -> UseTableSearchResult {
+    let default_query = options.default_query.clone().unwrap_or_default();
+    let input = use_signal({
+        let default_query = default_query.clone();
+        move || default_query
+    });
+    let query = use_signal(move || default_query);
+    let debounce = use_debounce(options.debounce_ms.unwrap_or(200));
+
+    let set_query = {
+        let mut input = input;
+        Rc::new(RefCell::new(move |next: String| {
+            input.set(next);
+            debounce.borrow_mut()(Rc::new(move |_props: DebounceProps| {
+                let mut query = query;
+                // Apply the latest input rather than the one that triggered this call
+                query.set(input.peek().clone());
+            }));
+        }))
+    };
+
+    let clear = {
+        let mut input = input;
+        let mut query = query;
+        Rc::new(RefCell::new(move || {
+            input.set(String::new());
+            query.set(String::new());
+        }))
+    };
+
+    let current_input = input.read().clone();
+    let current_query = query.read().clone();
+
+    UseTableSearchResult {
+        input: current_input,
+        query: current_query,
+        fuzzy: options.fuzzy.unwrap_or(false),
+        set_query,
+        clear,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_match_substring() {
+        let text_match = find_match("Alice Smith", "SMI", false).unwrap();
+
+        assert_eq!(text_match.ranges, vec![6..9]);
+        assert!(find_match("Alice", "", false).is_none());
+        assert!(find_match("Alice", "acl", false).is_none());
+        assert!(find_match("bob", "bob", false).unwrap().score > find_match("bobby", "bob", false).unwrap().score);
+        assert!(find_match("bobby", "bob", false).unwrap().score > find_match("a bob", "bob", false).unwrap().score);
+    }
+
+    #[test]
+    fn test_find_match_fuzzy() {
+        let text_match = find_match("Alice Smith", "alsm", true).unwrap();
+
+        assert_eq!(text_match.ranges, vec![0..2, 6..8]);
+        assert!(find_match("Alice", "xyz", true).is_none());
+        assert!(find_match("Alice", "lic", true).unwrap().score > text_match.score);
+        assert!(find_match("a_b_c", "abc", true).unwrap().score > find_match("a__b__c", "abc", true).unwrap().score);
+    }
+
+    #[test]
+    fn test_find_match_multibyte() {
+        let text_match = find_match("東京タワー", "タワ", false).unwrap();
+
+        assert_eq!(split_by_ranges("東京タワー", &text_match.ranges), vec![
+            ("東京".to_string(), false),
+            ("タワ".to_string(), true),
+            ("ー".to_string(), false),
+        ]);
+    }
+
+    #[test]
+    fn test_get_row_score() {
+        let cells = || vec!["Alice".to_string(), "Engineer".to_string()];
+
+        assert_eq!(get_row_score(cells(), "eng", false), Some(1500));
+        assert_eq!(get_row_score(cells(), "zzz", true), None);
+    }
+
+    #[test]
+    fn test_use_table_search_default_query() {
+        let mut dom = VirtualDom::new(|| {
+            let search = use_table_search(TableSearchOptions {
+                default_query: Some("ali".to_string()),
+                fuzzy: Some(true),
+                debounce_ms: Some(0),
+            });
+
+            assert_eq!(search.input, "ali");
+            assert_eq!(search.query, "ali");
+            assert!(search.find("Alice").is_some());
+
+            rsx! { div {} }
+        });
+
+        dom.rebuild_to_vec();
+    }
+}
@@ -0,0 +1,304 @@
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use crate::components::table_view::use_table::DataWithId;
+
+pub type ChildrenFuture<T> = Pin<Box<dyn Future<Output = Vec<T>>>>;
+/// Children of a row, known up front
+pub type GetChildrenFn<T> = Rc<dyn Fn(&T) -> Vec<T>>;
+/// Children of a row, loaded when it is first expanded
+pub type LoadChildrenFn<T> = Rc<dyn Fn(T) -> ChildrenFuture<T>>;
+type SetExpandedFn = Rc<RefCell<dyn FnMut(String, bool)>>;
+
+/// Tree position of a row in tree-data mode
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TreeNode {
+    /// ID of the parent row (`None` for root rows)
+    pub parent_id: Option<String>,
+    /// Whether the row has (or, before lazy loading, may have) children
+    pub has_children: bool,
+    pub is_expanded: bool,
+    /// Whether the children are being loaded
+    pub is_loading: bool,
+}
+
+/// Source of the children of the rows in tree-data mode
+#[derive(Clone)]
+pub struct TreeDataOptions<T> {
+    pub get_children: Option<GetChildrenFn<T>>,
+    /// Used when `get_children` is not set
+    pub load_children: Option<LoadChildrenFn<T>>,
+}
+
+/// Flatten a tree of rows, leaving out the children of collapsed rows
+///
+/// `get_children` gives `None` while the children are not loaded, which keeps the row
+/// expandable. Children are ordered by `sort`, and their IDs are prefixed by the ID of
+/// their parent (`"{parent}/{child}"`) so that they are unique across the tree.
+/// Render indices are reassigned in the flattened order.
+pub fn get_tree_rows<T: Clone + Hash + Eq>(
+    roots: Vec<DataWithId<T>>,
+    get_children: &dyn Fn(&DataWithId<T>) -> Option<Vec<T>>,
+    sort: &dyn Fn(Vec<T>) -> Vec<T>,
+    get_data_id: &dyn Fn(&T, usize) -> String,
+    is_expanded: &dyn Fn(&str) -> bool,
+    is_loading: &dyn Fn(&str) -> bool,
+) -> Vec<DataWithId<T>> {
+    let mut flattened = Vec::new();
+    push_rows(roots, None, 0, get_children, sort, get_data_id, is_expanded, is_loading, &mut flattened);
+
+    for (render_index, row) in flattened.iter_mut().enumerate() {
+        row.render_index = Some(render_index);
+    }
+    flattened
+}
+
+#[allow(clippy::too_many_arguments)]
+fn push_rows<T: Clone + Hash + Eq>(
+    rows: Vec<DataWithId<T>>,
+    parent_id: Option<&str>,
+    depth: usize,
+    get_children: &dyn Fn(&DataWithId<T>) -> Option<Vec<T>>,
+    sort: &dyn Fn(Vec<T>) -> Vec<T>,
+    get_data_id: &dyn Fn(&T, usize) -> String,
+    is_expanded: &dyn Fn(&str) -> bool,
+    is_loading: &dyn Fn(&str) -> bool,
+    flattened: &mut Vec<DataWithId<T>>,
+) {
+    for mut row in rows {
+        let children = get_children(&row);
+        let expanded = is_expanded(&row.id);
+        let id = row.id.clone();
+
+        row.depth = depth;
+        row.tree = Some(TreeNode {
+            parent_id: parent_id.map(str::to_string),
+            has_children: children.as_ref().is_none_or(|children| !children.is_empty()),
+            is_expanded: expanded,
+            is_loading: is_loading(&id),
+        });
+        flattened.push(row);
+
+        if let (true, Some(children)) = (expanded, children) {
+            let children = sort(children)
+                .into_iter()
+                .enumerate()
+                .map(|(index, child)| DataWithId {
+                    id: format!("{id}/{}", get_data_id(&child, index)),
+                    get: child,
+                    data_index: index,
+                    render_index: None,
+                    local_index: None,
+                    depth: 0,
+                    group_path: Vec::new(),
+                    group: None,
+                    tree: None,
+                })
+                .collect();
+            push_rows(children, Some(&id), depth + 1, get_children, sort, get_data_id, is_expanded, is_loading, flattened);
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct UseTreeDataResult<T: Clone + Hash + Eq + 'static> {
+    pub is_enabled: bool,
+    /// Visible rows of the tree in render order (the roots as given when disabled)
+    pub rows: Vec<DataWithId<T>>,
+    /// IDs of the expanded rows
+    pub expanded_ids: HashSet<String>,
+    /// Expand (`true`) or collapse a row, loading its children if needed
+    pub set_expanded: SetExpandedFn,
+    pub toggle: Rc<RefCell<dyn FnMut(String)>>,
+    pub collapse_all: Rc<RefCell<dyn FnMut()>>,
+}
+
+/// Hook flattening sorted root rows into a tree with expandable rows
+///
+/// `sort` orders the children of each row like the roots. Rows start collapsed.
+/// Children loaded by `load_children` are kept, so collapsing a row does not reload them.
+/// `None` disables it.
+pub fn use_tree_data<T: Clone + Hash + Eq + 'static>(
+    options: Option<TreeDataOptions<T>>,
+    roots: Vec<DataWithId<T>>,
+    sort: impl Fn(Vec<T>) -> Vec<T>,
+    get_data_id: impl Fn(&T, usize) -> String,
+) -> UseTreeDataResult<T> {
+    let expanded_ids = use_signal(HashSet::<String>::new);
+    let loaded_children = use_signal(HashMap::<String, Vec<T>>::new);
+    let loading_ids = use_signal(HashSet::<String>::new);
+
+    let Some(options) = options else {
+        return UseTreeDataResult {
+            is_enabled: false,
+            rows: roots,
+            expanded_ids: HashSet::new(),
+            set_expanded: Rc::new(RefCell::new(|_, _| {})),
+            toggle: Rc::new(RefCell::new(|_| {})),
+            collapse_all: Rc::new(RefCell::new(|| {})),
+        };
+    };
+
+    let rows = {
+        let expanded_ids = expanded_ids.read();
+        let loaded_children = loaded_children.read();
+        let loading_ids = loading_ids.read();
+        let get_children = |row: &DataWithId<T>| match &options.get_children {
+            Some(get_children) => Some(get_children(&row.get)),
+            None => loaded_children.get(&row.id).cloned(),
+        };
+        get_tree_rows(
+            roots,
+            &get_children,
+            &sort,
+            &get_data_id,
+            &|id| expanded_ids.contains(id),
+            &|id| loading_ids.contains(id),
+        )
+    };
+
+    let set_expanded = {
+        let (mut expanded_ids, mut loaded_children, mut loading_ids) = (expanded_ids, loaded_children, loading_ids);
+        // Rows whose children are yet to be loaded
+        let unloaded_rows: HashMap<String, T> = match &options.load_children {
+            Some(_) if options.get_children.is_none() => rows.iter()
+                .filter(|row| !loaded_children.peek().contains_key(&row.id))
+                .map(|row| (row.id.clone(), row.get.clone()))
+                .collect(),
+            _ => HashMap::new(),
+        };
+        let load_children = options.load_children.clone();
+
+        Rc::new(RefCell::new(move |id: String, expanded: bool| {
+            if expanded_ids.peek().contains(&id) != expanded {
+                expanded_ids.with_mut(|ids| {
+                    if expanded {
+                        ids.insert(id.clone());
+                    } else {
+                        ids.remove(&id);
+                    }
+                });
+            }
+
+            let (Some(load_children), Some(row)) = (&load_children, unloaded_rows.get(&id)) else {
+                return;
+            };
+            if !expanded || loading_ids.peek().contains(&id) {
+                return;
+            }
+            loading_ids.with_mut(|ids| ids.insert(id.clone()));
+            let children = load_children(row.clone());
+            spawn(async move {
+                let children = children.await;
+                loaded_children.with_mut(|loaded| loaded.insert(id.clone(), children));
+                loading_ids.with_mut(|ids| ids.remove(&id));
+            });
+        }))
+    };
+
+    let toggle = {
+        let set_expanded = set_expanded.clone();
+        Rc::new(RefCell::new(move |id: String| {
+            let expanded = expanded_ids.peek().contains(&id);
+            set_expanded.borrow_mut()(id, !expanded);
+        }))
+    };
+
+    let collapse_all = {
+        let mut expanded_ids = expanded_ids;
+        Rc::new(RefCell::new(move || {
+            expanded_ids.set(HashSet::new());
+        }))
+    };
+
+    let current_expanded_ids = expanded_ids.read().clone();
+
+    UseTreeDataResult {
+        is_enabled: true,
+        rows,
+        expanded_ids: current_expanded_ids,
+        set_expanded,
+        toggle,
+        collapse_all,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Node {
+        name: &'static str,
+        children: Vec<Node>,
+    }
+
+    fn node(name: &'static str, children: Vec<Node>) -> Node {
+        Node { name, children }
+    }
+
+    fn to_root(node: Node) -> DataWithId<Node> {
+        DataWithId {
+            id: node.name.to_string(),
+            get: node,
+            data_index: 0,
+            render_index: Some(0),
+            local_index: None,
+            depth: 0,
+            group_path: Vec::new(),
+            group: None,
+            tree: None,
+        }
+    }
+
+    #[test]
+    fn test_get_tree_rows() {
+        let root = node("root", vec![node("b", vec![node("leaf", Vec::new())]), node("a", Vec::new())]);
+        let sort_by_name = |mut nodes: Vec<Node>| {
+            nodes.sort_by_key(|node| node.name);
+            nodes
+        };
+
+        let rows = get_tree_rows(
+            vec![to_root(root)],
+            &|row| Some(row.get.children.clone()),
+            &sort_by_name,
+            &|node, _| node.name.to_string(),
+            &|id| id != "root/b",
+            &|_| false,
+        );
+        let summary: Vec<(&str, usize, bool)> = rows
+            .iter()
+            .map(|row| (row.id.as_str(), row.depth, row.tree.as_ref().is_some_and(|tree| tree.has_children)))
+            .collect();
+
+        assert_eq!(summary, vec![("root", 0, true), ("root/a", 1, false), ("root/b", 1, true)]);
+        assert_eq!(rows[2].tree.as_ref().and_then(|tree| tree.parent_id.clone()), Some("root".to_string()));
+        assert_eq!(rows[2].render_index, Some(2));
+    }
+
+    #[test]
+    fn test_get_tree_rows_unloaded() {
+        let rows = get_tree_rows(
+            vec![to_root(node("root", Vec::new()))],
+            &|_| None,
+            &|nodes| nodes,
+            &|node, _| node.name.to_string(),
+            &|_| true,
+            &|_| true,
+        );
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].tree, Some(TreeNode {
+            parent_id: None,
+            has_children: true,
+            is_expanded: true,
+            is_loading: true,
+        }));
+    }
+}
@@ -0,0 +1,93 @@
+/// Fenwick tree (binary indexed tree) of `f64` for prefix sums
+///
+/// Point updates, prefix sums and prefix searches all take O(log n).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FenwickTree {
+    // 1-indexed; `tree[i]` holds the sum of `(i - lowbit(i), i]`
+    tree: Vec<f64>,
+}
+
+impl FenwickTree {
+    pub fn new(len: usize) -> Self {
+        Self { tree: vec![0.0; len + 1] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add `delta` to the value at `index`
+    pub fn add(&mut self, index: usize, delta: f64) {
+        let mut node = index + 1;
+        while node < self.tree.len() {
+            self.tree[node] += delta;
+            node += node & node.wrapping_neg();
+        }
+    }
+
+    /// Sum of the values in `0..end` (`end` is clamped to the length)
+    pub fn prefix_sum(&self, end: usize) -> f64 {
+        let mut node = end.min(self.len());
+        let mut sum = 0.0;
+        while node > 0 {
+            sum += self.tree[node];
+            node -= node & node.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Largest `end` for which `predicate(end, prefix_sum(end))` holds
+    ///
+    /// The predicate must be monotonic (true up to some `end`, then false).
+    /// Returns `0` when it does not hold for any positive `end`.
+    pub fn search(&self, predicate: impl Fn(usize, f64) -> bool) -> usize {
+        let len = self.len();
+        let mut end = 0;
+        let mut sum = 0.0;
+        let mut step = if len == 0 { 0 } else { 1 << len.ilog2() };
+        while step > 0 {
+            let next = end + step;
+            if next <= len && predicate(next, sum + self.tree[next]) {
+                end = next;
+                sum += self.tree[next];
+            }
+            step >>= 1;
+        }
+        end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_sum() {
+        let mut tree = FenwickTree::new(5);
+        for (index, value) in [1.0, 2.0, 3.0, 4.0, 5.0].into_iter().enumerate() {
+            tree.add(index, value);
+        }
+        tree.add(2, -1.0);
+
+        assert_eq!(tree.prefix_sum(0), 0.0);
+        assert_eq!(tree.prefix_sum(3), 5.0);
+        assert_eq!(tree.prefix_sum(10), 14.0);
+    }
+
+    #[test]
+    fn test_search() {
+        let mut tree = FenwickTree::new(6);
+        for index in 0..6 {
+            tree.add(index, 10.0);
+        }
+
+        assert_eq!(tree.search(|_, sum| sum < 35.0), 3);
+        assert_eq!(tree.search(|_, sum| sum < 5.0), 0);
+        assert_eq!(tree.search(|_, sum| sum < 100.0), 6);
+        assert_eq!(FenwickTree::new(0).search(|_, _| true), 0);
+    }
+}
@@ -6,18 +6,23 @@ use wasm_bindgen::JsCast;
 ///
 /// isComposingでない AND (targetが入力欄でない OR カーソルが入力欄の先頭にある) ならtrue
 pub fn is_arrow_left_actionable(event: &Event<KeyboardData>) -> bool {
-    // Simple implementation without target element access for now
-    // In a real implementation, you would need to access the DOM target
-    !is_composing(event)
+    is_arrow_left_actionable_with_element(event, get_target_element(event).as_ref())
 }
 
 /// ArrowRight に反応して何かをしていいか判定
 ///
 /// isComposingでない AND (targetが入力欄でない OR カーソルが入力欄の末尾にある) ならtrue
 pub fn is_arrow_right_actionable(event: &Event<KeyboardData>) -> bool {
-    // Simple implementation without target element access for now
-    // In a real implementation, you would need to access the DOM target
-    !is_composing(event)
+    is_arrow_right_actionable_with_element(event, get_target_element(event).as_ref())
+}
+
+/// eventのtarget要素 (web以外のレンダラーでは取得できない)
+pub fn get_target_element(event: &Event<KeyboardData>) -> Option<web_sys::Element> {
+    event.data()
+        .downcast::<web_sys::KeyboardEvent>()?
+        .target()?
+        .dyn_into::<web_sys::Element>()
+        .ok()
 }
 
 /// 文字入力欄かどうか
@@ -32,38 +37,40 @@ pub fn is_str_input_element(element: &web_sys::Element) -> bool {
 /// カーソルが先頭にある文字入力欄かどうか (今発生したeventのtargetが渡されている前提)
 pub fn is_input_with_cursor_on_start(element: &web_sys::Element) -> bool {
     // カーソルが取得できるinputでなければ判定不可能
-    if !is_cursor_gettable_input_element(element) {
+    let Some(input_element) = get_cursor_gettable_input_element(element) else {
         return false;
-    }
+    };
     
-    // For now, return false as this requires complex DOM selection API access
-    // In a real implementation, you would use the Selection API to check cursor position
-    false
+    // 範囲選択中は先頭にあるとみなさない
+    matches!(
+        (input_element.selection_start(), input_element.selection_end()),
+        (Ok(Some(0)), Ok(Some(0)))
+    )
 }
 
 /// カーソルが末尾にある文字入力欄かどうか (今発生したeventのtargetが渡されている前提)
 pub fn is_input_with_cursor_on_end(element: &web_sys::Element) -> bool {
     // カーソルが取得できるinputでなければ判定不可能
-    if !is_cursor_gettable_input_element(element) {
+    let Some(input_element) = get_cursor_gettable_input_element(element) else {
         return false;
-    }
+    };
     
-    // For now, return false as this requires complex DOM selection API access
-    // In a real implementation, you would use the Selection API to check cursor position
-    false
+    // selectionStartはUTF-16単位
+    let end = input_element.value().encode_utf16().count() as u32;
+    matches!(
+        (input_element.selection_start(), input_element.selection_end()),
+        (Ok(Some(start)), Ok(Some(selection_end))) if start == end && selection_end == end
+    )
 }
 
 fn is_composing(event: &Event<KeyboardData>) -> bool {
     event.data().is_composing()
 }
 
-/// カーソル位置を取得できるinputかどうか
-fn is_cursor_gettable_input_element(element: &web_sys::Element) -> bool {
-    if let Some(input_element) = element.dyn_ref::<HtmlInputElement>() {
-        NORMAL_CURSOR_INPUT_TYPES.contains(&input_element.type_().as_str())
-    } else {
-        false
-    }
+/// カーソル位置を取得できるinput
+fn get_cursor_gettable_input_element(element: &web_sys::Element) -> Option<&HtmlInputElement> {
+    element.dyn_ref::<HtmlInputElement>()
+        .filter(|input_element| NORMAL_CURSOR_INPUT_TYPES.contains(&input_element.type_().as_str()))
 }
 
 /// 明らかに文字入力欄でなく、文字カーソルや左右移動が存在しなさそうなinput.type一覧 (仮)
@@ -83,10 +90,7 @@ const NORMAL_CURSOR_INPUT_TYPES: &[&str] = &[
     // "text", "search", "url", "tel", "password",
 ];
 
-// Extended functions with proper DOM access (to be implemented when needed)
-
-/// Advanced version with proper target element access
-/// This would be used in an actual implementation with access to the DOM event target
+/// target要素を指定して判定 (要素がなければisComposingだけで判定)
 pub fn is_arrow_left_actionable_with_element(
     event: &Event<KeyboardData>, 
     target_element: Option<&web_sys::Element>
@@ -98,8 +102,7 @@ pub fn is_arrow_left_actionable_with_element(
     }
 }
 
-/// Advanced version with proper target element access
-/// This would be used in an actual implementation with access to the DOM event target
+/// target要素を指定して判定 (要素がなければisComposingだけで判定)
 pub fn is_arrow_right_actionable_with_element(
     event: &Event<KeyboardData>, 
     target_element: Option<&web_sys::Element>
@@ -3,6 +3,7 @@ pub mod objects;
 pub mod merge;
 pub mod partialize_set_state;
 pub mod range;
+pub mod fenwick_tree;
 pub mod signal;
 pub mod input_event_util;
 pub mod obj_calc;
\ No newline at end of file
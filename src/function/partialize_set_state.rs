@@ -413,4 +413,34 @@ mod tests {
         
         dom.rebuild_to_vec();
     }
-}
\ No newline at end of file
+
+    #[derive(Clone, Debug, PartialEq, NestedValueOf, NestedValueSetter)]
+    struct AppState {
+        profile: AppProfile,
+    }
+
+    #[derive(Clone, Debug, PartialEq, NestedValueOf, NestedValueSetter)]
+    struct AppProfile {
+        name: String,
+        age: u32,
+    }
+
+    #[test]
+    fn test_partialize_set_state_deep_derived() {
+        use dioxus::prelude::*;
+        let mut dom = VirtualDom::new(|| {
+            let state = use_signal(|| AppState {
+                profile: AppProfile { name: "John".to_string(), age: 30 },
+            });
+
+            let set_name = partialize_set_state_deep(state, &["profile", "name"]);
+            set_name(NestedValue::String("Jane".to_string()));
+
+            assert_eq!(state.read().profile, AppProfile { name: "Jane".to_string(), age: 30 });
+
+            rsx! { div {} }
+        });
+
+        dom.rebuild_to_vec();
+    }
+}
@@ -1,2 +1,3 @@
 pub mod use_effect_event;
-pub mod use_debounce;
\ No newline at end of file
+pub mod use_debounce;
+pub mod use_lens;
\ No newline at end of file
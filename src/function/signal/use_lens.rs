@@ -0,0 +1,196 @@
+use dioxus::prelude::*;
+
+use crate::types::lens::Lens;
+
+/// Part of a signal's value focused by a lens
+///
+/// Reads go through a memo of the part, so a component reading it only re-renders
+/// when the part changes. Writes update the part in the source signal.
+pub struct LensSignal<S: 'static, A: 'static> {
+    source: Signal<S>,
+    lens: CopyValue<Lens<S, A>>,
+    value: Memo<A>,
+}
+
+impl<S: 'static, A: 'static> Clone for LensSignal<S, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: 'static, A: 'static> Copy for LensSignal<S, A> {}
+
+impl<S: 'static, A: 'static> PartialEq for LensSignal<S, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source && self.value == other.value
+    }
+}
+
+impl<S: 'static, A: PartialEq + Clone + 'static> LensSignal<S, A> {
+    /// Current value of the part, subscribing to its changes
+    pub fn get(&self) -> A {
+        self.value.cloned()
+    }
+
+    /// Current value of the part without subscribing
+    pub fn peek(&self) -> A {
+        self.value.peek().clone()
+    }
+
+    pub fn set(&self, value: A) {
+        let mut source = self.source;
+        self.lens.read().set(&mut source.write(), value);
+    }
+
+    pub fn with_mut(&self, f: impl FnOnce(&mut A)) {
+        let mut source = self.source;
+        self.lens.read().update(&mut source.write(), f);
+    }
+
+    /// Source signal of the part
+    pub fn source(&self) -> Signal<S> {
+        self.source
+    }
+}
+
+/// Hook focusing a signal on a part of its value with a lens
+///
+/// The lens of the first render is kept, like the initial value of `use_signal`.
+///
+/// # Arguments
+///
+/// * `signal` - The Signal holding the whole value
+/// * `lens` - The lens to the part
+///
+/// # Returns
+///
+/// A `LensSignal` reading and writing the part
+///
+/// # Example
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_ui::function::signal::use_lens::{use_lens, LensSignal};
+/// use dioxus_ui::types::lens::Lens;
+///
+/// #[derive(Clone, PartialEq, Lens)]
+/// struct User { profile: Profile }
+/// #[derive(Clone, PartialEq, Lens)]
+/// struct Profile { name: String }
+///
+/// #[component]
+/// fn UserForm() -> Element {
+///     let user = use_signal(|| User { profile: Profile { name: "John".to_string() } });
+///     let name = use_lens(user, UserLens::profile().then(ProfileLens::name()));
+///
+///     rsx! {
+///         NameInput { name }
+///     }
+/// }
+///
+/// #[component]
+/// fn NameInput(name: LensSignal<User, String>) -> Element {
+///     rsx! {
+///         input {
+///             value: name.get(),
+///             oninput: move |event| name.set(event.value()),
+///         }
+///     }
+/// }
+/// ```
+pub fn use_lens<S: 'static, A: PartialEq + Clone + 'static>(signal: Signal<S>, lens: Lens<S, A>) -> LensSignal<S, A> {
+    let lens = use_hook(|| CopyValue::new(lens));
+    let value = use_memo(move || lens.read().get(&signal.read()).clone());
+
+    LensSignal {
+        source: signal,
+        lens,
+        value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use crate::test_util::render_until_idle;
+
+    #[derive(Clone, Debug, PartialEq, Lens)]
+    struct User {
+        name: String,
+        profile: Profile,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Lens)]
+    struct Profile {
+        age: u32,
+    }
+
+    #[test]
+    fn test_use_lens() {
+        let mut dom = VirtualDom::new(|| {
+            let user = use_signal(|| User {
+                name: "John".to_string(),
+                profile: Profile { age: 30 },
+            });
+            let age = use_lens(user, UserLens::profile().then(ProfileLens::age()));
+
+            assert_eq!(age.get(), 30);
+
+            age.set(31);
+            assert_eq!(user.read().profile.age, 31);
+
+            age.with_mut(|age| *age += 1);
+            assert_eq!(user.read().profile, Profile { age: 32 });
+            assert_eq!(user.read().name, "John");
+            assert_eq!(age.peek(), 32);
+
+            rsx! { div {} }
+        });
+
+        dom.rebuild_to_vec();
+    }
+
+    thread_local! {
+        // Ages rendered by `AgeView`
+        static RENDERED_AGES: RefCell<Vec<u32>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn AgeView(age: LensSignal<User, u32>) -> Element {
+        RENDERED_AGES.with_borrow_mut(|ages| ages.push(age.get()));
+        rsx! { "{age.get()}" }
+    }
+
+    #[tokio::test]
+    async fn test_use_lens_rerenders_only_on_part_change() {
+        type Latest = Rc<RefCell<Option<Signal<User>>>>;
+
+        fn app(latest: Latest) -> Element {
+            let user = use_signal(|| User {
+                name: "John".to_string(),
+                profile: Profile { age: 30 },
+            });
+            let age = use_lens(user, UserLens::profile().then(ProfileLens::age()));
+            *latest.borrow_mut() = Some(user);
+
+            rsx! { AgeView { age } }
+        }
+
+        let latest: Latest = Rc::new(RefCell::new(None));
+        let mut dom = VirtualDom::new_with_props(app, latest.clone());
+        dom.rebuild_in_place();
+        let mut user = latest.borrow().unwrap();
+        assert_eq!(RENDERED_AGES.with_borrow(Vec::clone), vec![30]);
+
+        // Another field of the source
+        dom.in_runtime(|| user.write().name = "Jane".to_string());
+        render_until_idle(&mut dom).await;
+        assert_eq!(RENDERED_AGES.with_borrow(Vec::clone), vec![30]);
+
+        dom.in_runtime(|| user.write().profile.age = 31);
+        render_until_idle(&mut dom).await;
+        assert_eq!(RENDERED_AGES.with_borrow(Vec::clone), vec![30, 31]);
+    }
+}
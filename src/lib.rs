@@ -1,4 +1,9 @@
+// Lets derive macros refer to this crate as `::dioxus_ui` from within it
+extern crate self as dioxus_ui;
+
 pub mod components;
 pub mod prelude;
 pub mod function;
-pub mod types;
\ No newline at end of file
+pub mod types;
+#[cfg(test)]
+mod test_util;
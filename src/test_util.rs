@@ -0,0 +1,21 @@
+//! Helpers shared by the tests of the hooks and components
+
+use std::time::Duration;
+
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+
+/// Time without new work after which the dom counts as idle
+const IDLE_TIMEOUT: Duration = Duration::from_millis(100);
+/// Bound on the renders, so that a component rendering forever fails its assertions instead of hanging
+const MAX_RENDERS: usize = 20;
+
+/// Render a test dom until its effects, tasks and signal writes have all run
+pub(crate) async fn render_until_idle(dom: &mut VirtualDom) {
+    for _ in 0..MAX_RENDERS {
+        if tokio::time::timeout(IDLE_TIMEOUT, dom.wait_for_work()).await.is_err() {
+            return;
+        }
+        dom.render_immediate(&mut NoOpMutations);
+    }
+}
@@ -1,2 +1,197 @@
-// https://github.com/oxidecomputer/partial-struct
-// ↑レコードの部分適応はこれ使う
\ No newline at end of file
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub use dioxus_ui_macros::DeepPartial;
+
+/// Value that can be partially updated
+///
+/// Derive it with `#[derive(DeepPartial)]` to get a `FooPartial` struct whose fields are all `Option`.
+/// Nested structs that derive it are partialized recursively.
+///
+/// Leaf values (primitives, `String`, `Option`, `Vec`) are replaced as a whole,
+/// and `HashMap` entries are replaced per key.
+pub trait DeepPartial {
+    type Partial;
+
+    /// Overwrite the values set in the partial
+    fn apply(&mut self, partial: Self::Partial);
+
+    /// Merge two partials (values of `b` win)
+    fn merge(a: Self::Partial, b: Self::Partial) -> Self::Partial;
+
+    /// Partial with every value set
+    fn into_partial(self) -> Self::Partial;
+
+    /// Copy of the value with the partial applied
+    fn applied(mut self, partial: Self::Partial) -> Self
+    where
+        Self: Sized,
+    {
+        self.apply(partial);
+        self
+    }
+}
+
+macro_rules! impl_deep_partial_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl DeepPartial for $ty {
+                type Partial = Self;
+
+                fn apply(&mut self, partial: Self::Partial) {
+                    *self = partial;
+                }
+
+                fn merge(_a: Self::Partial, b: Self::Partial) -> Self::Partial {
+                    b
+                }
+
+                fn into_partial(self) -> Self::Partial {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_deep_partial_leaf!(
+    bool, char, String,
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+    f32, f64,
+);
+
+// `Some(None)` in a partial clears the value
+impl<T> DeepPartial for Option<T> {
+    type Partial = Self;
+
+    fn apply(&mut self, partial: Self::Partial) {
+        *self = partial;
+    }
+
+    fn merge(_a: Self::Partial, b: Self::Partial) -> Self::Partial {
+        b
+    }
+
+    fn into_partial(self) -> Self::Partial {
+        self
+    }
+}
+
+impl<T> DeepPartial for Vec<T> {
+    type Partial = Self;
+
+    fn apply(&mut self, partial: Self::Partial) {
+        *self = partial;
+    }
+
+    fn merge(_a: Self::Partial, b: Self::Partial) -> Self::Partial {
+        b
+    }
+
+    fn into_partial(self) -> Self::Partial {
+        self
+    }
+}
+
+impl<K: Eq + Hash, V> DeepPartial for HashMap<K, V> {
+    type Partial = Self;
+
+    fn apply(&mut self, partial: Self::Partial) {
+        self.extend(partial);
+    }
+
+    fn merge(mut a: Self::Partial, b: Self::Partial) -> Self::Partial {
+        a.extend(b);
+        a
+    }
+
+    fn into_partial(self) -> Self::Partial {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, DeepPartial)]
+    struct Address {
+        street: String,
+        city: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, DeepPartial)]
+    struct User {
+        name: String,
+        age: u32,
+        nickname: Option<String>,
+        tags: Vec<String>,
+        scores: HashMap<String, u32>,
+        address: Address,
+    }
+
+    fn user() -> User {
+        User {
+            name: "Alice".to_string(),
+            age: 20,
+            nickname: Some("Al".to_string()),
+            tags: vec!["a".to_string()],
+            scores: HashMap::from([("math".to_string(), 80)]),
+            address: Address { street: "Main".to_string(), city: "Tokyo".to_string() },
+        }
+    }
+
+    #[test]
+    fn test_apply_nested_partial() {
+        let mut value = user();
+        value.apply(UserPartial::new()
+            .with_age(21)
+            .with_nickname(None)
+            .with_scores(HashMap::from([("art".to_string(), 90)]))
+            .with_address(AddressPartial::new().with_city("Osaka".to_string())));
+
+        assert_eq!(value.name, "Alice");
+        assert_eq!(value.age, 21);
+        assert_eq!(value.nickname, None);
+        assert_eq!(value.scores.len(), 2);
+        assert_eq!(value.address, Address { street: "Main".to_string(), city: "Osaka".to_string() });
+    }
+
+    #[test]
+    fn test_merge_partials() {
+        let a = UserPartial::new()
+            .with_name("Bob".to_string())
+            .with_address(AddressPartial::new().with_street("First".to_string()));
+        let b = UserPartial::new()
+            .with_age(30)
+            .with_address(AddressPartial::new().with_city("Kyoto".to_string()));
+        let merged = User::merge(a, b);
+
+        assert_eq!(merged.name, Some("Bob".to_string()));
+        assert_eq!(merged.age, Some(30));
+        assert_eq!(merged.address, Some(AddressPartial {
+            street: Some("First".to_string()),
+            city: Some("Kyoto".to_string()),
+        }));
+    }
+
+    #[test]
+    fn test_into_partial_round_trip() {
+        let mut value = user();
+        value.tags.clear();
+        let applied = value.clone().applied(user().into());
+
+        assert_eq!(applied, user());
+        assert_eq!(UserPartial::from(user()).age, Some(20));
+    }
+
+    #[test]
+    fn test_size_from_partial() {
+        use crate::types::size::{PartialSize, Size};
+
+        let size = Size::from_partial(PartialSize::new().with_width(3.0));
+
+        assert_eq!(size, Size { width: 3.0, height: 1.0 });
+    }
+}
@@ -0,0 +1,126 @@
+use std::rc::Rc;
+
+pub use dioxus_ui_macros::Lens;
+
+type GetFn<S, A> = Rc<dyn Fn(&S) -> &A>;
+type GetMutFn<S, A> = Rc<dyn Fn(&mut S) -> &mut A>;
+
+/// Typed path from a value of `S` to a part of it of `A`
+///
+/// Derive `Lens` on a struct to get a `FooLens` with a lens per field,
+/// and compose them with `then` to reach nested fields.
+///
+/// Example
+/// ```rust
+/// use dioxus_ui::types::lens::Lens;
+///
+/// #[derive(Lens)]
+/// struct User { profile: Profile }
+/// #[derive(Lens)]
+/// struct Profile { name: String }
+///
+/// let mut user = User { profile: Profile { name: "John".to_string() } };
+/// let name = UserLens::profile().then(ProfileLens::name());
+/// name.set(&mut user, "Jane".to_string());
+/// assert_eq!(user.profile.name, "Jane");
+/// ```
+pub struct Lens<S, A> {
+    get: GetFn<S, A>,
+    get_mut: GetMutFn<S, A>,
+}
+
+impl<S, A> Clone for Lens<S, A> {
+    fn clone(&self) -> Self {
+        Self {
+            get: self.get.clone(),
+            get_mut: self.get_mut.clone(),
+        }
+    }
+}
+
+impl<S: 'static, A: 'static> Lens<S, A> {
+    pub fn new(get: fn(&S) -> &A, get_mut: fn(&mut S) -> &mut A) -> Self {
+        Self {
+            get: Rc::new(get),
+            get_mut: Rc::new(get_mut),
+        }
+    }
+
+    pub fn get<'a>(&self, source: &'a S) -> &'a A {
+        (self.get)(source)
+    }
+
+    pub fn get_mut<'a>(&self, source: &'a mut S) -> &'a mut A {
+        (self.get_mut)(source)
+    }
+
+    pub fn set(&self, source: &mut S, value: A) {
+        *self.get_mut(source) = value;
+    }
+
+    pub fn update(&self, source: &mut S, f: impl FnOnce(&mut A)) {
+        f(self.get_mut(source));
+    }
+
+    /// Lens focusing further into the part
+    pub fn then<B: 'static>(self, next: Lens<A, B>) -> Lens<S, B> {
+        let (get, get_mut) = (self.get, self.get_mut);
+        let (next_get, next_get_mut) = (next.get, next.get_mut);
+        Lens {
+            get: Rc::new(move |source| next_get(get(source))),
+            get_mut: Rc::new(move |source| next_get_mut(get_mut(source))),
+        }
+    }
+}
+
+/// Lens to the whole value
+pub fn identity_lens<S: 'static>() -> Lens<S, S> {
+    Lens::new(|source| source, |source| source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Lens)]
+    struct User {
+        name: String,
+        profile: Profile,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Lens)]
+    struct Profile {
+        age: u32,
+        #[lens(skip)]
+        #[allow(dead_code)]
+        cache: String,
+    }
+
+    fn user() -> User {
+        User {
+            name: "John".to_string(),
+            profile: Profile { age: 30, cache: String::new() },
+        }
+    }
+
+    #[test]
+    fn test_field_lens() {
+        let mut value = user();
+        let name = UserLens::name();
+
+        assert_eq!(name.get(&value), "John");
+        name.set(&mut value, "Jane".to_string());
+        assert_eq!(value.name, "Jane");
+    }
+
+    #[test]
+    fn test_composed_lens() {
+        let mut value = user();
+        let age = UserLens::profile().then(ProfileLens::age());
+
+        assert_eq!(*age.get(&value), 30);
+        age.update(&mut value, |age| *age += 1);
+        assert_eq!(value.profile.age, 31);
+        assert_eq!(identity_lens().then(age).get(&value), &31);
+    }
+}
@@ -4,5 +4,6 @@ pub mod partialized_tuple;
 pub mod required_fields;
 pub mod setter;
 pub mod deep_partial;
+pub mod lens;
 pub mod position;
 pub mod size;
\ No newline at end of file
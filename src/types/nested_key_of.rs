@@ -1,5 +1,9 @@
 use std::collections::HashMap;
 
+use crate::types::nested_value_of::NestedValue;
+
+pub use dioxus_ui_macros::NestedKeyOf;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum NestedKey {
     /// HashMap keys
@@ -40,20 +44,7 @@ where
 {
     fn get_nested_keys(&self) -> Vec<NestedKeyPath> {
         self.iter()
-            .flat_map(|(key, value)| {
-                let nested_keys = value.get_nested_keys();
-                if nested_keys.is_empty() {
-                    vec![vec![NestedKey::String(key.clone())]]
-                } else {
-                    nested_keys
-                        .into_iter()
-                        .map(|mut path| {
-                            path.insert(0, NestedKey::String(key.clone()));
-                            path
-                        })
-                        .collect()
-                }
-            })
+            .flat_map(|(key, value)| prefix_nested_keys(NestedKey::String(key.clone()), value.get_nested_keys()))
             .collect()
     }
 }
@@ -65,46 +56,60 @@ where
     fn get_nested_keys(&self) -> Vec<NestedKeyPath> {
         self.iter()
             .enumerate()
-            .flat_map(|(index, value)| {
-                let nested_keys = value.get_nested_keys();
-                if nested_keys.is_empty() {
-                    vec![vec![NestedKey::Number(index)]]
-                } else {
-                    nested_keys
-                        .into_iter()
-                        .map(|mut path| {
-                            path.insert(0, NestedKey::Number(index));
-                            path
-                        })
-                        .collect()
-                }
-            })
+            .flat_map(|(index, value)| prefix_nested_keys(NestedKey::Number(index), value.get_nested_keys()))
             .collect()
     }
 }
 
-impl NestedKeyOf for String {
-    fn get_nested_keys(&self) -> Vec<NestedKeyPath> {
-        vec![]
-    }
+macro_rules! impl_nested_key_of_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl NestedKeyOf for $ty {
+                fn get_nested_keys(&self) -> Vec<NestedKeyPath> {
+                    vec![]
+                }
+            }
+        )*
+    };
 }
 
-impl NestedKeyOf for i32 {
+impl_nested_key_of_leaf!(
+    String, bool,
+    i8, i16, i32, i64, isize,
+    u8, u16, u32, u64, usize,
+    f32, f64,
+);
+
+/// `None` has no keys, so the field itself is the path
+impl<T: NestedKeyOf> NestedKeyOf for Option<T> {
     fn get_nested_keys(&self) -> Vec<NestedKeyPath> {
-        vec![]
+        self.as_ref().map_or_else(Vec::new, |value| value.get_nested_keys())
     }
 }
 
-impl NestedKeyOf for f64 {
+impl NestedKeyOf for NestedValue {
     fn get_nested_keys(&self) -> Vec<NestedKeyPath> {
-        vec![]
+        match self {
+            NestedValue::Object(object) => object.get_nested_keys(),
+            NestedValue::Array(items) => items.get_nested_keys(),
+            _ => vec![],
+        }
     }
 }
 
-impl NestedKeyOf for bool {
-    fn get_nested_keys(&self) -> Vec<NestedKeyPath> {
-        vec![]
+/// Prefix the paths of a value with its key
+/// A value without nested keys (a leaf) becomes the path of the key alone
+pub fn prefix_nested_keys(key: NestedKey, nested_keys: Vec<NestedKeyPath>) -> Vec<NestedKeyPath> {
+    if nested_keys.is_empty() {
+        return vec![vec![key]];
     }
+    nested_keys
+        .into_iter()
+        .map(|mut path| {
+            path.insert(0, key.clone());
+            path
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -244,4 +249,49 @@ mod tests {
             NestedKey::Number(1)
         ]));
     }
-}
\ No newline at end of file
+
+    #[derive(NestedKeyOf)]
+    struct Profile {
+        #[nested(rename = "displayName")]
+        name: String,
+        tags: Vec<String>,
+        #[nested(skip)]
+        #[allow(dead_code)]
+        cache: String,
+    }
+
+    #[derive(NestedKeyOf)]
+    enum Contact {
+        None,
+        Email(String),
+        Phone { country: u32, number: String },
+    }
+
+    #[test]
+    fn test_derive_nested_key_of_struct() {
+        let profile = Profile {
+            name: "Alice".to_string(),
+            tags: vec!["a".to_string()],
+            cache: String::new(),
+        };
+
+        assert_eq!(profile.get_nested_keys(), vec![
+            vec![NestedKey::String("displayName".to_string())],
+            vec![NestedKey::String("tags".to_string()), NestedKey::Number(0)],
+        ]);
+    }
+
+    #[test]
+    fn test_derive_nested_key_of_enum() {
+        let phone = Contact::Phone { country: 81, number: "000".to_string() };
+
+        assert_eq!(Contact::None.get_nested_keys(), Vec::<NestedKeyPath>::new());
+        assert_eq!(Contact::Email("a@example.com".to_string()).get_nested_keys(), vec![
+            vec![NestedKey::String("Email".to_string())],
+        ]);
+        assert_eq!(phone.get_nested_keys(), vec![
+            vec![NestedKey::String("Phone".to_string()), NestedKey::String("country".to_string())],
+            vec![NestedKey::String("Phone".to_string()), NestedKey::String("number".to_string())],
+        ]);
+    }
+}
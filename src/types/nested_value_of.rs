@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use serde_json::Value;
 
+pub use dioxus_ui_macros::{NestedValueOf, NestedValueSetter};
+
 #[derive(Debug, Clone)]
 pub enum NestedValue {
     String(String),
@@ -235,6 +237,277 @@ impl NestedValueSetter for NestedValue {
     }
 }
 
+macro_rules! impl_from_number {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<$ty> for NestedValue {
+                fn from(value: $ty) -> Self {
+                    NestedValue::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i8, i16, i64, isize, u8, u16, u32, u64, usize, f32);
+
+impl<T> From<Option<T>> for NestedValue
+where
+    T: Into<NestedValue>,
+{
+    fn from(value: Option<T>) -> Self {
+        value.map_or(NestedValue::Null, |value| value.into())
+    }
+}
+
+/// Error converting a `NestedValue` into a typed value
+#[derive(Debug, Clone, PartialEq)]
+pub enum NestedValueError {
+    /// The value has another shape (e.g. a string for a number)
+    TypeMismatch { expected: &'static str },
+    /// A struct field is missing from the object
+    MissingField(String),
+    /// No variant of the enum has the name
+    UnknownVariant(String),
+    /// The path can't be set on the value
+    InvalidPath(Vec<String>),
+}
+
+impl std::fmt::Display for NestedValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NestedValueError::TypeMismatch { expected } => write!(f, "expected {}", expected),
+            NestedValueError::MissingField(key) => write!(f, "missing field `{}`", key),
+            NestedValueError::UnknownVariant(name) => write!(f, "unknown variant `{}`", name),
+            NestedValueError::InvalidPath(keys) => write!(f, "invalid path `{}`", keys.join(".")),
+        }
+    }
+}
+
+impl std::error::Error for NestedValueError {}
+
+/// Conversion from `NestedValue` back to a typed value
+///
+/// Derive it with `#[derive(NestedValueSetter)]`, which also implements `TryFrom<NestedValue>`.
+pub trait FromNestedValue: Sized {
+    fn from_nested_value(value: NestedValue) -> Result<Self, NestedValueError>;
+}
+
+impl FromNestedValue for NestedValue {
+    fn from_nested_value(value: NestedValue) -> Result<Self, NestedValueError> {
+        Ok(value)
+    }
+}
+
+impl FromNestedValue for String {
+    fn from_nested_value(value: NestedValue) -> Result<Self, NestedValueError> {
+        match value {
+            NestedValue::String(s) => Ok(s),
+            _ => Err(NestedValueError::TypeMismatch { expected: "string" }),
+        }
+    }
+}
+
+impl FromNestedValue for bool {
+    fn from_nested_value(value: NestedValue) -> Result<Self, NestedValueError> {
+        match value {
+            NestedValue::Bool(b) => Ok(b),
+            _ => Err(NestedValueError::TypeMismatch { expected: "bool" }),
+        }
+    }
+}
+
+impl FromNestedValue for f64 {
+    fn from_nested_value(value: NestedValue) -> Result<Self, NestedValueError> {
+        match value {
+            NestedValue::Number(n) => Ok(n),
+            _ => Err(NestedValueError::TypeMismatch { expected: "number" }),
+        }
+    }
+}
+
+impl FromNestedValue for f32 {
+    fn from_nested_value(value: NestedValue) -> Result<Self, NestedValueError> {
+        f64::from_nested_value(value).map(|n| n as f32)
+    }
+}
+
+// Numbers are stored as f64, so integers must be whole and in range
+macro_rules! impl_from_nested_value_integer {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl FromNestedValue for $ty {
+                fn from_nested_value(value: NestedValue) -> Result<Self, NestedValueError> {
+                    let n = f64::from_nested_value(value)?;
+                    if n.fract() == 0.0 && n >= <$ty>::MIN as f64 && n <= <$ty>::MAX as f64 {
+                        Ok(n as $ty)
+                    } else {
+                        Err(NestedValueError::TypeMismatch { expected: stringify!($ty) })
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_nested_value_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl<T: FromNestedValue> FromNestedValue for Option<T> {
+    fn from_nested_value(value: NestedValue) -> Result<Self, NestedValueError> {
+        match value {
+            NestedValue::Null => Ok(None),
+            value => T::from_nested_value(value).map(Some),
+        }
+    }
+}
+
+impl<T: FromNestedValue> FromNestedValue for Vec<T> {
+    fn from_nested_value(value: NestedValue) -> Result<Self, NestedValueError> {
+        into_array(value)?
+            .into_iter()
+            .map(T::from_nested_value)
+            .collect()
+    }
+}
+
+impl<T: FromNestedValue> FromNestedValue for HashMap<String, T> {
+    fn from_nested_value(value: NestedValue) -> Result<Self, NestedValueError> {
+        into_object(value)?
+            .into_iter()
+            .map(|(key, item)| Ok((key, T::from_nested_value(item)?)))
+            .collect()
+    }
+}
+
+/// Items of an array value
+pub fn into_array(value: NestedValue) -> Result<Vec<NestedValue>, NestedValueError> {
+    match value {
+        NestedValue::Array(items) => Ok(items),
+        _ => Err(NestedValueError::TypeMismatch { expected: "array" }),
+    }
+}
+
+/// Entries of an object value
+pub fn into_object(value: NestedValue) -> Result<HashMap<String, NestedValue>, NestedValueError> {
+    match value {
+        NestedValue::Object(object) => Ok(object),
+        _ => Err(NestedValueError::TypeMismatch { expected: "object" }),
+    }
+}
+
+/// Take a field out of an object value and convert it
+///
+/// A missing field is read as `Null`, so `Option` fields may be left out.
+pub fn take_field<T: FromNestedValue>(object: &mut HashMap<String, NestedValue>, key: &str) -> Result<T, NestedValueError> {
+    match object.remove(key) {
+        Some(value) => T::from_nested_value(value),
+        None => T::from_nested_value(NestedValue::Null).map_err(|_| NestedValueError::MissingField(key.to_string())),
+    }
+}
+
+/// Take an item out of an array value and convert it
+pub fn take_item<T: FromNestedValue>(items: &mut [NestedValue], index: usize) -> Result<T, NestedValueError> {
+    match items.get_mut(index) {
+        Some(item) => T::from_nested_value(std::mem::replace(item, NestedValue::Null)),
+        None => T::from_nested_value(NestedValue::Null).map_err(|_| NestedValueError::MissingField(index.to_string())),
+    }
+}
+
+/// Name and payload of an enum value
+///
+/// Unit variants are a string of the name, and the others an object with the name as the only key.
+pub fn split_variant(value: NestedValue) -> Result<(String, NestedValue), NestedValueError> {
+    match value {
+        NestedValue::String(name) => Ok((name, NestedValue::Null)),
+        NestedValue::Object(object) if object.len() == 1 => {
+            let mut entries = object.into_iter();
+            entries.next().ok_or(NestedValueError::TypeMismatch { expected: "enum variant" })
+        }
+        _ => Err(NestedValueError::TypeMismatch { expected: "enum variant" }),
+    }
+}
+
+/// Replace the target with the converted value
+/// Returns `false` and leaves the target as is when the conversion fails
+pub fn replace_nested_value<T: FromNestedValue>(target: &mut T, value: NestedValue) -> bool {
+    match T::from_nested_value(value) {
+        Ok(value) => {
+            *target = value;
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+macro_rules! impl_nested_value_setter_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl NestedValueSetter for $ty {
+                fn set_nested_value(&mut self, keys: &[&str], value: NestedValue) -> bool {
+                    keys.is_empty() && replace_nested_value(self, value)
+                }
+            }
+        )*
+    };
+}
+
+impl_nested_value_setter_leaf!(
+    String, bool,
+    i8, i16, i32, i64, isize,
+    u8, u16, u32, u64, usize,
+    f32, f64,
+);
+
+impl<T> NestedValueSetter for Option<T>
+where
+    T: NestedValueSetter + FromNestedValue,
+{
+    fn set_nested_value(&mut self, keys: &[&str], value: NestedValue) -> bool {
+        match self {
+            _ if keys.is_empty() => replace_nested_value(self, value),
+            Some(inner) => inner.set_nested_value(keys, value),
+            None => false,
+        }
+    }
+}
+
+impl<T> NestedValueSetter for Vec<T>
+where
+    T: NestedValueSetter + FromNestedValue,
+{
+    fn set_nested_value(&mut self, keys: &[&str], value: NestedValue) -> bool {
+        let Some((first, rest)) = keys.split_first() else {
+            return replace_nested_value(self, value);
+        };
+        match first.parse::<usize>().ok().and_then(|index| self.get_mut(index)) {
+            Some(item) => item.set_nested_value(rest, value),
+            None => false,
+        }
+    }
+}
+
+impl<T> NestedValueSetter for HashMap<String, T>
+where
+    T: NestedValueSetter + FromNestedValue,
+{
+    fn set_nested_value(&mut self, keys: &[&str], value: NestedValue) -> bool {
+        let Some((first, rest)) = keys.split_first() else {
+            return replace_nested_value(self, value);
+        };
+        if let Some(item) = self.get_mut(*first) {
+            return item.set_nested_value(rest, value);
+        }
+        // New entries can only be inserted as a whole
+        match (rest.is_empty(), T::from_nested_value(value)) {
+            (true, Ok(item)) => {
+                self.insert(first.to_string(), item);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -461,4 +734,77 @@ mod tests {
         let result = data.get_nested_value(&[]);
         assert!(matches!(result, Some(NestedValue::String(s)) if s == "test"));
     }
+
+    #[derive(Clone, Debug, PartialEq, NestedValueOf, NestedValueSetter)]
+    struct Profile {
+        #[nested(rename = "displayName")]
+        name: String,
+        age: u32,
+        nickname: Option<String>,
+        #[nested(skip)]
+        cache: String,
+    }
+
+    #[derive(Clone, Debug, PartialEq, NestedValueOf, NestedValueSetter)]
+    enum Contact {
+        None,
+        Email(String),
+        Phone { country: u32, number: String },
+    }
+
+    fn profile() -> Profile {
+        Profile { name: "Alice".to_string(), age: 20, nickname: None, cache: "cached".to_string() }
+    }
+
+    #[test]
+    fn test_derive_nested_value_of() {
+        let value = profile();
+
+        assert!(matches!(value.get_nested_value(&["displayName"]), Some(NestedValue::String(s)) if s == "Alice"));
+        assert!(matches!(value.get_nested_value(&["age"]), Some(NestedValue::Number(n)) if n == 20.0));
+        assert!(matches!(value.get_nested_value(&["nickname"]), Some(NestedValue::Null)));
+        assert!(value.get_nested_value(&["cache"]).is_none());
+        assert!(value.get_nested_value(&["name"]).is_none());
+
+        let phone = Contact::Phone { country: 81, number: "000".to_string() };
+        assert!(matches!(phone.get_nested_value(&["Phone", "country"]), Some(NestedValue::Number(n)) if n == 81.0));
+        assert!(matches!(NestedValue::from(Contact::None), NestedValue::String(s) if s == "None"));
+    }
+
+    #[test]
+    fn test_derive_nested_value_setter() {
+        let mut value = profile();
+
+        assert!(value.set_nested_value(&["displayName"], NestedValue::String("Bob".to_string())));
+        assert!(value.set_nested_value(&["nickname"], NestedValue::String("B".to_string())));
+        assert!(!value.set_nested_value(&["age"], NestedValue::Number(1.5)));
+        assert!(!value.set_nested_value(&["age"], NestedValue::String("1".to_string())));
+        assert!(!value.set_nested_value(&["cache"], NestedValue::String("x".to_string())));
+        assert_eq!(value, Profile { name: "Bob".to_string(), age: 20, nickname: Some("B".to_string()), cache: "cached".to_string() });
+
+        let mut contact = Contact::Phone { country: 81, number: "000".to_string() };
+        assert!(contact.set_nested_value(&["Phone", "number"], NestedValue::String("111".to_string())));
+        assert_eq!(contact, Contact::Phone { country: 81, number: "111".to_string() });
+        assert!(!contact.set_nested_value(&["Email", "x"], NestedValue::Null));
+        assert!(contact.set_nested_value(&["Email"], NestedValue::String("a@example.com".to_string())));
+        assert_eq!(contact, Contact::Email("a@example.com".to_string()));
+    }
+
+    #[test]
+    fn test_derive_nested_value_round_trip() {
+        let value = profile();
+        let restored = Profile::try_from(NestedValue::from(value.clone()));
+
+        // Skipped fields are not stored
+        assert_eq!(restored, Ok(Profile { cache: String::new(), ..value }));
+        assert_eq!(Contact::try_from(NestedValue::from(Contact::None)), Ok(Contact::None));
+        assert_eq!(
+            Profile::try_from(NestedValue::Object(HashMap::new())),
+            Err(NestedValueError::MissingField("displayName".to_string())),
+        );
+        assert_eq!(
+            Contact::try_from(NestedValue::String("Fax".to_string())),
+            Err(NestedValueError::UnknownVariant("Fax".to_string())),
+        );
+    }
 }
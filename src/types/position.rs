@@ -1,8 +1,7 @@
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
-use crate::function::merge::merge_two;
+use crate::types::deep_partial::DeepPartial;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, DeepPartial)]
 pub struct Position {
     pub x: f64,
     pub y: f64,
@@ -14,15 +13,7 @@ impl Position {
     }
 
     pub fn from_partial(partial: PartialPosition) -> Self {
-        let init_json = json!({
-            "x": 0.0,
-            "y": 0.0
-        });
-        
-        let partial_json = serde_json::to_value(partial).unwrap_or(Value::Null);
-        let merged = merge_two(init_json, partial_json);
-        
-        serde_json::from_value(merged).unwrap_or_else(|_| Self::init())
+        Self::init().applied(partial)
     }
 
     pub fn from_number(value: f64) -> Self {
@@ -37,11 +28,7 @@ impl Position {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
-pub struct PartialPosition {
-    pub x: Option<f64>,
-    pub y: Option<f64>,
-}
+pub type PartialPosition = PositionPartial;
 
 #[derive(Debug, Clone)]
 pub enum PositionInput {
@@ -1,8 +1,6 @@
 use std::rc::Rc;
-use crate::types::{
-    nested_key_of::NestedKeyOf,
-    nested_value_of::{NestedValue, NestedValueOf, NestedValueSetter},
-};
+use dioxus::prelude::*;
+use crate::types::nested_value_of::{NestedValue, NestedValueError, NestedValueOf, NestedValueSetter};
 
 /// A type alias for a state setter function.
 /// 
@@ -12,14 +10,20 @@ use crate::types::{
 /// # Example
 /// ```rust
 /// use std::rc::Rc;
-/// use your_crate::types::setter::{Setter, SetStateAction};
+/// use dioxus_ui::types::setter::{Setter, SetStateAction};
 /// 
-/// let setter: Setter<i32> = Rc::new(|action| {
+/// let setter: Setter<i32> = Rc::new(|_action: SetStateAction<i32>| {
 ///     // Handle the state update
 /// });
+/// setter(SetStateAction::Value(1));
 /// ```
 pub type Setter<T> = Rc<dyn Fn(SetStateAction<T>)>;
 
+/// A state setter reporting whether the update could be applied.
+///
+/// The state is left unchanged when an error is returned.
+pub type TrySetter<T> = Rc<dyn Fn(SetStateAction<T>) -> Result<(), NestedValueError>>;
+
 /// Represents an action to update state.
 /// 
 /// This enum allows for two types of state updates:
@@ -29,13 +33,13 @@ pub type Setter<T> = Rc<dyn Fn(SetStateAction<T>)>;
 /// # Example
 /// ```rust
 /// use std::rc::Rc;
-/// use your_crate::types::setter::SetStateAction;
+/// use dioxus_ui::types::setter::SetStateAction;
 /// 
 /// // Direct value update
 /// let action1 = SetStateAction::Value(42);
 /// 
 /// // Function-based update
-/// let action2 = SetStateAction::Function(Rc::new(|prev| prev + 1));
+/// let action2: SetStateAction<i32> = SetStateAction::Function(Rc::new(|prev| prev + 1));
 /// ```
 #[derive(Clone)]
 pub enum SetStateAction<T> {
@@ -60,7 +64,7 @@ impl SetterUtils {
     /// # Example
     /// ```rust
     /// use std::rc::Rc;
-    /// use your_crate::types::setter::{SetStateAction, SetterUtils};
+    /// use dioxus_ui::types::setter::{SetStateAction, SetterUtils};
     /// 
     /// let action = SetStateAction::Value(42);
     /// let result = SetterUtils::to_value(action, 0);
@@ -90,16 +94,18 @@ impl SetterUtils {
     /// 
     /// # Example
     /// ```rust
+    /// use std::cell::Cell;
     /// use std::rc::Rc;
-    /// use your_crate::types::setter::{SetStateAction, SetterUtils};
+    /// use dioxus_ui::types::setter::{SetStateAction, SetterUtils};
     /// 
-    /// let setter = SetterUtils::from(|update_fn: Rc<dyn Fn(i32) -> i32>| {
-    ///     // This would typically update actual state
-    ///     let new_value = update_fn(current_state);
-    ///     // Update your state management system here
+    /// let state = Rc::new(Cell::new(0));
+    /// let current_state = state.clone();
+    /// let setter = SetterUtils::from(move |update_fn: Rc<dyn Fn(i32) -> i32>| {
+    ///     current_state.set(update_fn(current_state.get()));
     /// });
     /// 
     /// setter(SetStateAction::Value(42));
+    /// assert_eq!(state.get(), 42);
     /// ```
     pub fn from<T: Clone + 'static>(
         use_fn: impl Fn(Rc<dyn Fn(T) -> T>) + 'static,
@@ -111,10 +117,62 @@ impl SetterUtils {
         })
     }
 
+    /// Applies an action to the value at a key path of the state.
+    /// 
+    /// Only the value at the path is converted, so the rest of the state
+    /// (including fields skipped by `#[nested(skip)]`) is kept as is.
+    /// An action resulting in `None` leaves the state unchanged.
+    /// 
+    /// # Arguments
+    /// * `prev` - The previous state value
+    /// * `keys` - The path to the nested value (empty for the whole state)
+    /// * `set_state_action` - The action to apply to the nested value
+    /// 
+    /// # Returns
+    /// The new state value, or an error when the path doesn't exist or the value doesn't fit it
+    /// 
+    /// # Example
+    /// ```rust
+    /// use dioxus_ui::types::nested_value_of::{NestedValue, NestedValueOf, NestedValueSetter};
+    /// use dioxus_ui::types::setter::{SetStateAction, SetterUtils};
+    /// 
+    /// #[derive(Clone, NestedValueOf, NestedValueSetter)]
+    /// struct User { profile: Profile }
+    /// #[derive(Clone, NestedValueOf, NestedValueSetter)]
+    /// struct Profile { name: String }
+    /// 
+    /// let user = User { profile: Profile { name: "John".to_string() } };
+    /// let next = SetterUtils::try_set_nested_value(
+    ///     user,
+    ///     &["profile", "name"],
+    ///     SetStateAction::Value(Some(NestedValue::String("Jane".to_string()))),
+    /// ).unwrap();
+    /// assert_eq!(next.profile.name, "Jane");
+    /// ```
+    pub fn try_set_nested_value<T: Clone + NestedValueOf + NestedValueSetter>(
+        prev: T,
+        keys: &[&str],
+        set_state_action: SetStateAction<Option<NestedValue>>,
+    ) -> Result<T, NestedValueError> {
+        let current_value = prev.get_nested_value(keys);
+        let Some(new_value) = Self::to_value(set_state_action, current_value.clone()) else {
+            return Ok(prev);
+        };
+        let mut next = prev.clone();
+        if next.set_nested_value(keys, new_value) {
+            return Ok(next);
+        }
+        Err(match current_value {
+            Some(current_value) => NestedValueError::TypeMismatch { expected: kind_name(&current_value) },
+            None => NestedValueError::InvalidPath(keys.iter().map(|key| key.to_string()).collect()),
+        })
+    }
+
     /// Creates a partial setter that can update a single nested field.
     /// 
     /// This method allows you to create setters for specific fields within a nested
     /// data structure, enabling granular state updates.
+    /// Updates that can't be applied (see `try_set_nested_value`) leave the state unchanged.
     /// 
     /// # Arguments
     /// * `set_state` - The main state setter
@@ -124,54 +182,39 @@ impl SetterUtils {
     /// 
     /// # Example
     /// ```rust
+    /// use std::cell::RefCell;
     /// use std::rc::Rc;
-    /// use your_crate::types::setter::{SetStateAction, SetterUtils};
+    /// use dioxus_ui::types::nested_value_of::{NestedValue, NestedValueOf, NestedValueSetter};
+    /// use dioxus_ui::types::setter::{SetStateAction, SetterUtils};
     /// 
-    /// let main_setter = Rc::new(|action| {
-    ///     // Update main state
+    /// #[derive(Clone, NestedValueOf, NestedValueSetter)]
+    /// struct User { name: String }
+    /// 
+    /// let user = Rc::new(RefCell::new(User { name: "Jane".to_string() }));
+    /// let state = user.clone();
+    /// let main_setter = SetterUtils::from(move |update: Rc<dyn Fn(User) -> User>| {
+    ///     let next = update(state.borrow().clone());
+    ///     *state.borrow_mut() = next;
     /// });
     /// 
     /// let partial_setter = SetterUtils::partial_once(main_setter);
     /// let name_setter = partial_setter("name");
     /// 
     /// name_setter(SetStateAction::Value(Some(NestedValue::String("John".to_string()))));
+    /// assert_eq!(user.borrow().name, "John");
     /// ```
-    pub fn partial_once<T: Clone + NestedValueOf + Into<NestedValue> + 'static>(
+    pub fn partial_once<T: Clone + NestedValueOf + NestedValueSetter + 'static>(
         set_state: Setter<T>,
     ) -> impl Fn(&str) -> Setter<Option<NestedValue>> {
-        move |key: &str| {
-            let key = key.to_string();
-            let set_state = set_state.clone();
-            SetterUtils::from(move |use_fn: Rc<dyn Fn(Option<NestedValue>) -> Option<NestedValue>>| {
-                let key = key.clone();
-                let use_fn = use_fn.clone();
-                set_state(SetStateAction::Function(Rc::new(move |prev| {
-                    // Get current nested value
-                    let current_value = prev.get_nested_value(&[&key]);
-                    
-                    // Apply the update function
-                    let updated_value = use_fn(current_value);
-                    
-                    // If we have an updated value, create a new state with the change
-                    if let Some(new_value) = updated_value {
-                        let mut new_state: NestedValue = prev.clone().into();
-                        if new_state.set_nested_value(&[&key], new_value) {
-                            // Try to convert back to T - this is simplified
-                            // In practice, you'd need proper conversion logic
-                            return prev; // For now, return unchanged
-                        }
-                    }
-                    
-                    prev
-                })));
-            })
-        }
+        let partial = Self::partial(set_state);
+        move |key: &str| partial(&[key.to_string()])
     }
 
     /// Creates a partial setter that can update deeply nested fields.
     /// 
     /// This method allows you to create setters for specific fields at any depth
     /// within a nested data structure using a key path.
+    /// Updates that can't be applied (see `try_set_nested_value`) leave the state unchanged.
     /// 
     /// # Arguments
     /// * `set_state` - The main state setter
@@ -181,57 +224,291 @@ impl SetterUtils {
     /// 
     /// # Example
     /// ```rust
+    /// use std::cell::RefCell;
     /// use std::rc::Rc;
-    /// use your_crate::types::setter::{SetStateAction, SetterUtils};
+    /// use dioxus_ui::types::nested_value_of::{NestedValue, NestedValueOf, NestedValueSetter};
+    /// use dioxus_ui::types::setter::{SetStateAction, SetterUtils};
+    /// 
+    /// #[derive(Clone, NestedValueOf, NestedValueSetter)]
+    /// struct User { profile: Profile }
+    /// #[derive(Clone, NestedValueOf, NestedValueSetter)]
+    /// struct Profile { name: String }
     /// 
-    /// let main_setter = Rc::new(|action| {
-    ///     // Update main state
+    /// let user = Rc::new(RefCell::new(User { profile: Profile { name: "John".to_string() } }));
+    /// let state = user.clone();
+    /// let main_setter = SetterUtils::from(move |update: Rc<dyn Fn(User) -> User>| {
+    ///     let next = update(state.borrow().clone());
+    ///     *state.borrow_mut() = next;
     /// });
     /// 
     /// let partial_setter = SetterUtils::partial(main_setter);
-    /// let deep_setter = partial_setter(&["user".to_string(), "profile".to_string(), "name".to_string()]);
+    /// let deep_setter = partial_setter(&["profile".to_string(), "name".to_string()]);
     /// 
     /// deep_setter(SetStateAction::Value(Some(NestedValue::String("Jane".to_string()))));
+    /// assert_eq!(user.borrow().profile.name, "Jane");
     /// ```
-    pub fn partial<T: Clone + NestedKeyOf + NestedValueOf + Into<NestedValue> + 'static>(
+    pub fn partial<T: Clone + NestedValueOf + NestedValueSetter + 'static>(
         set_state: Setter<T>,
     ) -> impl Fn(&[String]) -> Setter<Option<NestedValue>> {
         move |keys: &[String]| {
-            if keys.is_empty() {
-                // Return a no-op setter for empty keys
-                return SetterUtils::from(move |_use_fn: Rc<dyn Fn(Option<NestedValue>) -> Option<NestedValue>>| {
-                    // Do nothing
-                });
-            }
-            
             let keys = keys.to_vec();
             let set_state = set_state.clone();
-            SetterUtils::from(move |use_fn: Rc<dyn Fn(Option<NestedValue>) -> Option<NestedValue>>| {
+            Rc::new(move |set_state_action: SetStateAction<Option<NestedValue>>| {
                 let keys = keys.clone();
-                let use_fn = use_fn.clone();
-                set_state(SetStateAction::Function(Rc::new(move |prev| {
-                    // Convert keys to &str slice
+                set_state(SetStateAction::Function(Rc::new(move |prev: T| {
                     let key_refs: Vec<&str> = keys.iter().map(|s| s.as_str()).collect();
-                    
-                    // Get current nested value
-                    let current_value = prev.get_nested_value(&key_refs);
-                    
-                    // Apply the update function
-                    let updated_value = use_fn(current_value);
-                    
-                    // If we have an updated value, create a new state with the change
-                    if let Some(new_value) = updated_value {
-                        let mut new_state: NestedValue = prev.clone().into();
-                        if new_state.set_nested_value(&key_refs, new_value) {
-                            // Try to convert back to T - this is simplified
-                            // In practice, you'd need proper conversion logic
-                            return prev; // For now, return unchanged
-                        }
-                    }
-                    
-                    prev
+                    Self::try_set_nested_value(prev.clone(), &key_refs, set_state_action.clone())
+                        .unwrap_or(prev)
                 })));
             })
         }
     }
+
+    /// Creates a setter bound to a Dioxus `Signal`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use dioxus::prelude::*;
+    /// use dioxus_ui::types::setter::{SetStateAction, SetterUtils};
+    /// 
+    /// #[component]
+    /// fn Counter() -> Element {
+    ///     let count = use_signal(|| 0);
+    ///     let set_count = SetterUtils::from_signal(count);
+    /// 
+    ///     rsx! {
+    ///         button {
+    ///             onclick: move |_| set_count(SetStateAction::Function(Rc::new(|prev| prev + 1))),
+    ///             "{count}"
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn from_signal<T: Clone + 'static>(signal: Signal<T>) -> Setter<T> {
+        Rc::new(move |set_state_action| {
+            let mut signal = signal;
+            let prev = signal.peek().clone();
+            signal.set(Self::to_value(set_state_action, prev));
+        })
+    }
+
+    /// Creates a partial setter that can update a single field of a `Signal`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use dioxus::prelude::*;
+    /// use dioxus_ui::types::nested_value_of::{NestedValue, NestedValueOf, NestedValueSetter};
+    /// use dioxus_ui::types::setter::{SetStateAction, SetterUtils};
+    /// 
+    /// #[derive(Clone, NestedValueOf, NestedValueSetter)]
+    /// struct User { name: String }
+    /// 
+    /// #[component]
+    /// fn NameInput() -> Element {
+    ///     let user = use_signal(|| User { name: "John".to_string() });
+    ///     let set_name = SetterUtils::signal_partial_once(user)("name");
+    /// 
+    ///     rsx! {
+    ///         input {
+    ///             value: user.read().name.clone(),
+    ///             oninput: move |event| set_name(SetStateAction::Value(Some(NestedValue::String(event.value())))),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn signal_partial_once<T: Clone + NestedValueOf + NestedValueSetter + 'static>(
+        signal: Signal<T>,
+    ) -> impl Fn(&str) -> Setter<Option<NestedValue>> {
+        Self::partial_once(Self::from_signal(signal))
+    }
+
+    /// Creates a partial setter that can update deeply nested fields of a `Signal`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use dioxus::prelude::*;
+    /// use dioxus_ui::types::nested_value_of::{NestedValue, NestedValueOf, NestedValueSetter};
+    /// use dioxus_ui::types::setter::{SetStateAction, SetterUtils};
+    /// 
+    /// #[derive(Clone, NestedValueOf, NestedValueSetter)]
+    /// struct User { profile: Profile }
+    /// #[derive(Clone, NestedValueOf, NestedValueSetter)]
+    /// struct Profile { name: String }
+    /// 
+    /// #[component]
+    /// fn NameInput() -> Element {
+    ///     let user = use_signal(|| User { profile: Profile { name: "John".to_string() } });
+    ///     let set_name = SetterUtils::signal_partial(user)(&["profile".to_string(), "name".to_string()]);
+    /// 
+    ///     rsx! {
+    ///         input {
+    ///             value: user.read().profile.name.clone(),
+    ///             oninput: move |event| set_name(SetStateAction::Value(Some(NestedValue::String(event.value())))),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn signal_partial<T: Clone + NestedValueOf + NestedValueSetter + 'static>(
+        signal: Signal<T>,
+    ) -> impl Fn(&[String]) -> Setter<Option<NestedValue>> {
+        Self::partial(Self::from_signal(signal))
+    }
+
+    /// Creates a partial setter for a `Signal` that reports errors.
+    /// 
+    /// The signal is only written when the update succeeds.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use dioxus::prelude::*;
+    /// use dioxus_ui::types::nested_value_of::{NestedValue, NestedValueOf, NestedValueSetter};
+    /// use dioxus_ui::types::setter::{SetStateAction, SetterUtils};
+    /// 
+    /// #[derive(Clone, NestedValueOf, NestedValueSetter)]
+    /// struct User { age: u32 }
+    /// 
+    /// let mut dom = VirtualDom::new(|| {
+    ///     let user = use_signal(|| User { age: 30 });
+    ///     let set_age = SetterUtils::try_signal_partial(user)(&["age".to_string()]);
+    /// 
+    ///     // `age` can't hold a string
+    ///     assert!(set_age(SetStateAction::Value(Some(NestedValue::String("x".to_string())))).is_err());
+    ///     assert_eq!(user.peek().age, 30);
+    /// 
+    ///     rsx! { div {} }
+    /// });
+    /// dom.rebuild_in_place();
+    /// ```
+    pub fn try_signal_partial<T: Clone + NestedValueOf + NestedValueSetter + 'static>(
+        signal: Signal<T>,
+    ) -> impl Fn(&[String]) -> TrySetter<Option<NestedValue>> {
+        move |keys: &[String]| {
+            let keys = keys.to_vec();
+            Rc::new(move |set_state_action| {
+                let mut signal = signal;
+                let key_refs: Vec<&str> = keys.iter().map(|s| s.as_str()).collect();
+                let next = Self::try_set_nested_value(signal.peek().clone(), &key_refs, set_state_action)?;
+                signal.set(next);
+                Ok(())
+            })
+        }
+    }
+}
+
+/// Name of the kind of a value, for the errors of the setters
+fn kind_name(value: &NestedValue) -> &'static str {
+    match value {
+        NestedValue::String(_) => "string",
+        NestedValue::Number(_) => "number",
+        NestedValue::Bool(_) => "bool",
+        NestedValue::Array(_) => "array",
+        NestedValue::Object(_) => "object",
+        NestedValue::Null => "null",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Clone, Debug, PartialEq, NestedValueOf, NestedValueSetter)]
+    struct User {
+        name: String,
+        profile: Profile,
+    }
+
+    #[derive(Clone, Debug, PartialEq, NestedValueOf, NestedValueSetter)]
+    struct Profile {
+        age: u32,
+    }
+
+    fn user() -> User {
+        User { name: "John".to_string(), profile: Profile { age: 30 } }
+    }
+
+    fn string(value: &str) -> SetStateAction<Option<NestedValue>> {
+        SetStateAction::Value(Some(NestedValue::String(value.to_string())))
+    }
+
+    #[test]
+    fn test_try_set_nested_value() {
+        let next = SetterUtils::try_set_nested_value(user(), &["name"], string("Jane"));
+        assert_eq!(next, Ok(User { name: "Jane".to_string(), ..user() }));
+
+        let increment = SetStateAction::Function(Rc::new(|prev: Option<NestedValue>| match prev {
+            Some(NestedValue::Number(n)) => Some(NestedValue::Number(n + 1.0)),
+            prev => prev,
+        }));
+        let next = SetterUtils::try_set_nested_value(user(), &["profile", "age"], increment);
+        assert_eq!(next.map(|user| user.profile.age), Ok(31));
+
+        assert_eq!(
+            SetterUtils::try_set_nested_value(user(), &["profile", "age"], string("x")),
+            Err(NestedValueError::TypeMismatch { expected: "number" }),
+        );
+        assert_eq!(
+            SetterUtils::try_set_nested_value(user(), &["name", "first"], string("x")),
+            Err(NestedValueError::InvalidPath(vec!["name".to_string(), "first".to_string()])),
+        );
+    }
+
+    #[test]
+    fn test_try_set_nested_value_keeps_the_rest_of_the_state() {
+        #[derive(Clone, Debug, PartialEq, NestedValueOf, NestedValueSetter)]
+        struct Record {
+            id: u64,
+            name: String,
+            #[nested(skip)]
+            cache: Vec<String>,
+        }
+
+        // Not representable as `f64`
+        let id = (1 << 53) + 1;
+        let record = Record { id, name: "John".to_string(), cache: vec!["x".to_string()] };
+
+        let next = SetterUtils::try_set_nested_value(record.clone(), &["name"], string("Jane"));
+        assert_eq!(next, Ok(Record { name: "Jane".to_string(), ..record }));
+    }
+
+    #[test]
+    fn test_partial_writes_nested_value() {
+        let state = Rc::new(RefCell::new(user()));
+        let set_state: Setter<User> = {
+            let state = state.clone();
+            Rc::new(move |set_state_action| {
+                let prev = state.borrow().clone();
+                *state.borrow_mut() = SetterUtils::to_value(set_state_action, prev);
+            })
+        };
+
+        SetterUtils::partial_once(set_state.clone())("name")(string("Jane"));
+        SetterUtils::partial(set_state.clone())(&["profile".to_string(), "age".to_string()])(
+            SetStateAction::Value(Some(NestedValue::Number(40.0))),
+        );
+        // Invalid updates are ignored
+        SetterUtils::partial_once(set_state)("name")(SetStateAction::Value(Some(NestedValue::Bool(true))));
+
+        assert_eq!(*state.borrow(), User { name: "Jane".to_string(), profile: Profile { age: 40 } });
+    }
+
+    #[test]
+    fn test_signal_partial() {
+        let mut dom = VirtualDom::new(|| {
+            let state = use_signal(user);
+
+            SetterUtils::signal_partial_once(state)("name")(string("Jane"));
+            assert_eq!(state.read().name, "Jane");
+
+            let set_age = SetterUtils::try_signal_partial(state)(&["profile".to_string(), "age".to_string()]);
+            assert!(set_age(string("x")).is_err());
+            assert!(set_age(SetStateAction::Value(Some(NestedValue::Number(31.0)))).is_ok());
+            assert_eq!(state.read().profile.age, 31);
+
+            rsx! { div {} }
+        });
+
+        dom.rebuild_to_vec();
+    }
 }
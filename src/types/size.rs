@@ -1,19 +1,14 @@
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
-use crate::function::merge::merge_two;
+use crate::types::deep_partial::DeepPartial;
 use super::position::Position;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, DeepPartial)]
 pub struct Size {
     pub width: f64,
     pub height: f64,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
-pub struct PartialSize {
-    pub width: Option<f64>,
-    pub height: Option<f64>,
-}
+pub type PartialSize = SizePartial;
 
 impl Size {
     pub fn init() -> Self {
@@ -21,15 +16,7 @@ impl Size {
     }
 
     pub fn from_partial(partial: PartialSize) -> Self {
-        let init_json = json!({
-            "width": 1.0,
-            "height": 1.0
-        });
-        
-        let partial_json = serde_json::to_value(partial).unwrap_or(Value::Null);
-        let merged = merge_two(init_json, partial_json);
-        
-        serde_json::from_value(merged).unwrap_or_else(|_| Self::init())
+        Self::init().applied(partial)
     }
 
     pub fn from_number(value: f64) -> Self {